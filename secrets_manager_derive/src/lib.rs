@@ -0,0 +1,183 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! # Secrets Manager Derive
+//!
+//! Derive macro generating `secrets_manager::InjectSecrets` implementations that
+//! populate config structs from a `SecretClient`.
+//!
+//! Each field annotated with `#[secret("key-name")]` is read from the client:
+//!
+//! - `String` and `SecretValue` fields require the secret to exist
+//! - `Option<String>` and `Option<SecretValue>` fields are `None` when the secret is not found
+//!
+//! Fields without the attribute are initialized with `Default::default()`.
+//!
+//! It also derives `secrets_manager::SecretKey` for the marker types naming the
+//! secret read by the axum `InjectableSecret` extractor, from a
+//! `#[secret_key("key-name")]` attribute.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Field, Fields, GenericArgument, LitStr, PathArguments, Type,
+    parse_macro_input, spanned::Spanned,
+};
+
+/// Derives `secrets_manager::InjectSecrets` for a struct with named fields.
+#[proc_macro_derive(InjectSecrets, attributes(secret))]
+pub fn derive_inject_secrets(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives `secrets_manager::SecretKey` for a type annotated with `#[secret_key("key-name")]`.
+#[proc_macro_derive(SecretKey, attributes(secret_key))]
+pub fn derive_secret_key(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_secret_key(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Expands the `SecretKey` derive for the given type.
+fn expand_secret_key(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Some(attr) = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("secret_key"))
+    else {
+        return Err(syn::Error::new(
+            input.span(),
+            "SecretKey requires a #[secret_key(\"key-name\")] attribute",
+        ));
+    };
+
+    let key = attr.parse_args::<LitStr>()?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::secrets_manager::SecretKey for #name #ty_generics #where_clause {
+            const KEY: &'static str = #key;
+        }
+    })
+}
+
+/// Expands the derive for the given struct.
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "InjectSecrets can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(
+            input.span(),
+            "InjectSecrets can only be derived for structs with named fields",
+        ));
+    };
+
+    let initializers = fields
+        .named
+        .iter()
+        .map(field_initializer)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::secrets_manager::InjectSecrets for #name #ty_generics #where_clause {
+            fn from_secrets(
+                client: &dyn ::secrets_manager::SecretClient,
+            ) -> ::std::result::Result<Self, ::secrets_manager::errors::SecretsManagerError> {
+                ::std::result::Result::Ok(Self {
+                    #(#initializers),*
+                })
+            }
+        }
+    })
+}
+
+/// Generates the initializer of a single field.
+fn field_initializer(field: &Field) -> syn::Result<TokenStream2> {
+    let ident = &field.ident;
+
+    let Some(key) = secret_key(field)? else {
+        return Ok(quote! { #ident: ::std::default::Default::default() });
+    };
+
+    let value = match option_inner(&field.ty) {
+        Some(inner) => {
+            let convert = conversion(inner)?;
+            quote! { client.get_optional(#key)?.map(#convert) }
+        }
+        None => {
+            let convert = conversion(&field.ty)?;
+            quote! { (#convert)(client.get_by_key(#key)?) }
+        }
+    };
+
+    Ok(quote! { #ident: #value })
+}
+
+/// Returns the key of the `#[secret("key")]` attribute of a field, if any.
+fn secret_key(field: &Field) -> syn::Result<Option<LitStr>> {
+    field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("secret"))
+        .map(|attr| attr.parse_args::<LitStr>())
+        .transpose()
+}
+
+/// Returns the inner type of an `Option<T>`, if the type is an option.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let segment = last_segment(ty)?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns the function converting a `SecretValue` into the given field type.
+fn conversion(ty: &Type) -> syn::Result<TokenStream2> {
+    match last_segment(ty).map(|segment| segment.ident.to_string()) {
+        Some(name) if name == "String" => {
+            Ok(quote! { ::secrets_manager::SecretValue::into_string })
+        }
+        Some(name) if name == "SecretValue" => Ok(quote! { ::std::convert::identity }),
+        _ => Err(syn::Error::new(
+            ty.span(),
+            "#[secret] fields must be String, SecretValue or an Option of either",
+        )),
+    }
+}
+
+/// Returns the last path segment of a type.
+fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    path.path.segments.last()
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Secret access metrics.
+//!
+//! This module records how secrets are accessed through the `metrics` crate, so
+//! that any installed `metrics::Recorder` (Prometheus, StatsD, ...) receives
+//! per-key access and error counters under a configurable prefix.
+
+use metrics::{
+    Unit, counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The prefix of the metric names when none is configured.
+pub(crate) const DEFAULT_PREFIX: &str = "secrets_manager";
+
+/// Records the metrics of a single secret client under a common prefix.
+pub(crate) struct AccessMetrics {
+    /// The name of the per-key access counter
+    total: String,
+    /// The name of the per-key error counter
+    error: String,
+    /// The name of the shared value cache hit ratio gauge
+    cache_hit_ratio: String,
+    /// The number of shared value cache lookups
+    cache_lookups: AtomicU64,
+    /// The number of shared value cache hits
+    cache_hits: AtomicU64,
+}
+
+impl AccessMetrics {
+    /// Describes the metrics to the installed recorder.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of every metric name, e.g. `secrets_manager`
+    ///
+    /// # Returns
+    ///
+    /// The metrics of a client
+    pub(crate) fn register(prefix: &str) -> AccessMetrics {
+        let metrics = AccessMetrics {
+            total: format!("{prefix}.get_by_key.total"),
+            error: format!("{prefix}.get_by_key.error"),
+            cache_hit_ratio: format!("{prefix}.cache_hit_ratio"),
+            cache_lookups: AtomicU64::new(0),
+            cache_hits: AtomicU64::new(0),
+        };
+
+        describe_counter!(metrics.total.clone(), "Number of secret accesses, by key");
+        describe_counter!(
+            metrics.error.clone(),
+            "Number of failed secret accesses, by key"
+        );
+        describe_gauge!(
+            metrics.cache_hit_ratio.clone(),
+            "Ratio of shared secret values served from cache"
+        );
+        describe_histogram!(
+            build_duration_name(prefix),
+            Unit::Milliseconds,
+            "Duration of the retrieval of the secret when building the client"
+        );
+
+        metrics
+    }
+
+    /// Records an access to a key and whether it succeeded.
+    pub(crate) fn record_access(&self, key: &str, success: bool) {
+        counter!(self.total.clone(), "key" => key.to_string()).increment(1);
+
+        if !success {
+            counter!(self.error.clone(), "key" => key.to_string()).increment(1);
+        }
+    }
+
+    /// Records a lookup in the shared value cache and updates the hit ratio.
+    pub(crate) fn record_cache_lookup(&self, hit: bool) {
+        let lookups = self.cache_lookups.fetch_add(1, Ordering::Relaxed) + 1;
+        let hits = match hit {
+            true => self.cache_hits.fetch_add(1, Ordering::Relaxed) + 1,
+            false => self.cache_hits.load(Ordering::Relaxed),
+        };
+
+        gauge!(self.cache_hit_ratio.clone()).set(hits as f64 / lookups as f64);
+    }
+}
+
+/// Records the duration of the retrieval of a secret by a builder.
+///
+/// # Arguments
+///
+/// * `prefix` - The prefix of the metric name
+/// * `duration_ms` - The duration of the retrieval, in milliseconds
+pub(crate) fn record_build_duration(prefix: &str, duration_ms: f64) {
+    histogram!(build_duration_name(prefix)).record(duration_ms);
+}
+
+/// Returns the name of the build duration histogram.
+fn build_duration_name(prefix: &str) -> String {
+    format!("{prefix}.build_duration_ms")
+}
@@ -0,0 +1,209 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Secret access auditing.
+//!
+//! This module defines the `AuditHook` trait notified on every secret access and
+//! the `AuditedSecretClient` wrapper that invokes it, along with hooks writing to
+//! stdout or forwarding events to a `tokio` channel.
+
+use crate::{HealthCheckableSecretClient, SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use serde_json::{Map, Value};
+use tokio::sync::mpsc::Sender;
+use tracing::error;
+
+/// A record of a single secret access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+    /// The key that was accessed
+    pub key: String,
+    /// Whether the secret was successfully retrieved
+    pub success: bool,
+    /// The identity of the caller that accessed the secret
+    pub caller: String,
+}
+
+/// Trait notified every time a secret is accessed through an `AuditedSecretClient`.
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+pub trait AuditHook: Send + Sync {
+    /// Records a secret access.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key that was accessed
+    /// * `success` - Whether the secret was successfully retrieved
+    /// * `caller` - The identity of the caller that accessed the secret
+    fn on_access(&self, key: &str, success: bool, caller: &str);
+}
+
+/// Audit hook that prints every secret access to stdout.
+#[derive(Default)]
+pub struct StdoutAuditHook;
+
+impl AuditHook for StdoutAuditHook {
+    fn on_access(&self, key: &str, success: bool, caller: &str) {
+        println!("secret access: key={key} success={success} caller={caller}");
+    }
+}
+
+/// Audit hook that forwards every secret access to a `tokio` channel.
+///
+/// Events are sent without blocking; if the channel is full or closed the
+/// event is dropped and an error is logged.
+pub struct ChannelAuditHook(pub Sender<AuditEvent>);
+
+impl AuditHook for ChannelAuditHook {
+    fn on_access(&self, key: &str, success: bool, caller: &str) {
+        let event = AuditEvent {
+            key: key.to_string(),
+            success,
+            caller: caller.to_string(),
+        };
+
+        if let Err(err) = self.0.try_send(event) {
+            error!(error = err.to_string(), "failure to send audit event");
+        }
+    }
+}
+
+/// Client that reports every secret access of an inner client to an audit hook.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{AuditedSecretClient, FakeSecretClient, SecretClient, StdoutAuditHook};
+///
+/// let client = AuditedSecretClient::new(FakeSecretClient::new(), StdoutAuditHook, "payments");
+///
+/// // Prints "secret access: key=api-key success=true caller=payments"
+/// let secret = client.get_by_key("api-key");
+/// ```
+pub struct AuditedSecretClient<C: SecretClient, A: AuditHook> {
+    /// The client secrets are retrieved from
+    inner: C,
+    /// The hook notified on every access
+    hook: A,
+    /// The identity reported as the caller of every access
+    caller: String,
+}
+
+#[async_trait]
+impl<C: SecretClient, A: AuditHook> SecretClient for AuditedSecretClient<C, A> {
+    /// Retrieves a secret from the inner client and reports the access to the hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let result = self.inner.get_by_key(key);
+        self.hook.on_access(key, result.is_ok(), &self.caller);
+        result
+    }
+
+    /// Lists the keys available from the inner client.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys reported by the inner client
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.inner.list_keys()
+    }
+
+    /// Retrieves a secret asynchronously from the inner client and reports the access to the hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let result = self.inner.get_by_key_async(key).await;
+        self.hook.on_access(key, result.is_ok(), &self.caller);
+        result
+    }
+
+    /// Retrieves a secret from the inner client as a JSON object and reports the access to the hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        let result = self.inner.get_json_object_by_key(key);
+        self.hook.on_access(key, result.is_ok(), &self.caller);
+        result
+    }
+
+    /// Retrieves a secret from the inner client as a JSON array and reports the access to the hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        let result = self.inner.get_json_array_by_key(key);
+        self.hook.on_access(key, result.is_ok(), &self.caller);
+        result
+    }
+}
+
+#[async_trait]
+impl<C: HealthCheckableSecretClient, A: AuditHook> HealthCheckableSecretClient
+    for AuditedSecretClient<C, A>
+{
+    /// Checks whether the backend of the inner client is reachable.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend of the inner client is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+impl<C: SecretClient, A: AuditHook> AuditedSecretClient<C, A> {
+    /// Creates a new `AuditedSecretClient` wrapping the given client.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client secrets are retrieved from
+    /// * `hook` - The hook notified on every access
+    /// * `caller` - The identity reported as the caller, e.g. the service name
+    ///
+    /// # Returns
+    ///
+    /// A new `AuditedSecretClient`
+    pub fn new(inner: C, hook: A, caller: &str) -> AuditedSecretClient<C, A> {
+        AuditedSecretClient {
+            inner,
+            hook,
+            caller: caller.to_string(),
+        }
+    }
+}
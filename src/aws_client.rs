@@ -7,30 +7,641 @@
 //! This module provides the `AWSSecretClient` which implements the `SecretClient` trait
 //! for retrieving secrets from AWS Secrets Manager.
 
-use crate::{SecretClient, errors::SecretsManagerError};
+#[cfg(feature = "metrics")]
+use crate::access_metrics::AccessMetrics;
+use crate::{
+    BinarySecretClient, HealthCheckableSecretClient, KeyPrefix, SecretClient, SecretEvent,
+    SecretEventKind, SecretValue,
+    client::{json_array, json_object},
+    errors::SecretsManagerError,
+    events::EventSink,
+    lookup::{lookup, lookup_value, resolve},
+};
+use async_trait::async_trait;
+use aws_sdk_secretsmanager::{
+    Client,
+    error::DisplayErrorContext,
+    operation::RequestId,
+    primitives::{DateTime, DateTimeFormat},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
 #[cfg(test)]
 use mockall::*;
 #[cfg(feature = "mocks")]
 use mockall::*;
-use serde_json::Value;
-use tracing::error;
+use rand::Rng;
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    env, fmt,
+    sync::{
+        Arc, OnceLock, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, SystemTime},
+};
+use tokio::runtime::Handle;
+use tracing::{Instrument, Span, debug, debug_span, error, field, info_span, warn};
+
+/// The name of the backend, as logged when a secret is not found.
+const BACKEND: &str = "AWS Secrets Manager";
+
+/// The variable that must be `true` for environment variables to override secrets.
+const ALLOW_ENV_OVERRIDE_VAR: &str = "SECRETS_MANAGER_ALLOW_ENV_OVERRIDE";
+
+/// The prefix of the environment variables overriding secrets.
+const ENV_OVERRIDE_PREFIX: &str = "SECRETS_MANAGER_";
+
+/// How long `is_available` waits for AWS when no timeout is configured.
+pub(crate) const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
 
 /// Client for accessing secrets from AWS Secrets Manager.
 ///
 /// This client stores secrets in memory after they've been retrieved from AWS
-/// and provides methods to access them by key.
-#[derive(Default)]
+/// and provides methods to access them by key. Its `Debug` output lists the
+/// secret keys but never their values.
+///
+/// Cloning is shallow: the clone holds a copy of the secrets loaded at the time
+/// of the clone and shares the underlying AWS client, but it is not a live
+/// handle, so reloading one client does not update the other.
 pub struct AWSSecretClient {
     /// The cached secrets as a JSON Value
     pub(crate) secrets: Value,
+    /// The Secrets Manager client used to fetch fresh values, if any
+    pub(crate) client: Option<Client>,
+    /// The request the cached secrets were retrieved with
+    pub(crate) request: SecretRequest,
+    /// The version id of the loaded secret, if known
+    pub(crate) version_id: Option<String>,
+    /// The ARN of the loaded secret, if known
+    pub(crate) arn: Option<String>,
+    /// The id of the AWS request the secret was loaded with, if known
+    pub(crate) request_id: Option<String>,
+    /// The time the secrets were last loaded
+    pub(crate) loaded_at: SystemTime,
+    /// The secrets loaded on first access, if the client was built lazily
+    pub(crate) lazy: Option<Arc<LazySecrets>>,
+    /// The metadata of the secret, if requested when building the client
+    pub(crate) metadata: Option<SecretMetadata>,
+    /// The secrets already handed out as shared strings
+    pub(crate) shared: RwLock<HashMap<String, Arc<str>>>,
+    /// The metrics secret accesses are recorded with, if enabled
+    #[cfg(feature = "metrics")]
+    pub(crate) metrics: Option<Arc<AccessMetrics>>,
+    /// The sink the lifecycle events are sent to, if any
+    pub(crate) event_sink: Option<Arc<EventSink>>,
+}
+
+/// The metadata of a secret, as returned by `DescribeSecret`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SecretMetadata {
+    /// The friendly name of the secret
+    pub name: String,
+    /// The ARN of the secret
+    pub arn: String,
+    /// The time the secret was created, if reported
+    pub created_date: Option<SystemTime>,
+    /// The tags attached to the secret, e.g. `Env` and `Owner`
+    pub tags: HashMap<String, String>,
+}
+
+/// Identifies the secret, and optionally its version, to retrieve from AWS.
+#[derive(Default, Clone)]
+pub(crate) struct SecretRequest {
+    /// The name/id of the secret
+    pub(crate) secret_id: String,
+    /// The unique identifier of the version to retrieve
+    pub(crate) version_id: Option<String>,
+    /// The staging label of the version to retrieve, e.g. `AWSPREVIOUS`
+    pub(crate) version_stage: Option<String>,
+    /// The transform applied to the top-level keys of the retrieved secret
+    pub(crate) key_transform: Option<fn(&str) -> String>,
+    /// The sigil removed from the keys given to the client
+    pub(crate) key_prefix: KeyPrefix,
+    /// How long `is_available` waits for AWS, `DEFAULT_HEALTH_CHECK_TIMEOUT` if not set
+    pub(crate) health_check_timeout: Option<Duration>,
+    /// Whether environment variables override secrets, read once when building the client
+    pub(crate) allow_env_override: bool,
+    /// The maximum number of attempts made to retrieve the secret
+    pub(crate) max_attempts: u32,
+    /// The delay before the first retry, doubled on every subsequent retry
+    pub(crate) base_delay: Duration,
+}
+
+/// The secrets of a lazily built client, loaded in the background on first access.
+#[derive(Default)]
+pub(crate) struct LazySecrets {
+    /// The secret, once retrieved
+    fetched: OnceLock<FetchedSecret>,
+    /// Whether the secret is being retrieved
+    loading: AtomicBool,
+}
+
+/// A secret retrieved from AWS along with its metadata.
+pub(crate) struct FetchedSecret {
+    /// The parsed secret JSON
+    pub(crate) secrets: Value,
+    /// The version id of the retrieved secret
+    pub(crate) version_id: Option<String>,
+    /// The ARN of the retrieved secret
+    pub(crate) arn: Option<String>,
+    /// The id of the AWS request the secret was retrieved with
+    pub(crate) request_id: Option<String>,
+    /// The time the secret was retrieved
+    pub(crate) loaded_at: SystemTime,
+}
+
+impl AWSSecretClient {
+    /// Builds a client from a secret retrieved from AWS.
+    pub(crate) fn from_fetched(
+        fetched: FetchedSecret,
+        client: Client,
+        request: SecretRequest,
+    ) -> AWSSecretClient {
+        AWSSecretClient {
+            secrets: fetched.secrets,
+            client: Some(client),
+            request,
+            version_id: fetched.version_id,
+            arn: fetched.arn,
+            request_id: fetched.request_id,
+            loaded_at: fetched.loaded_at,
+            lazy: None,
+            metadata: None,
+            shared: RwLock::default(),
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            event_sink: None,
+        }
+    }
+
+    /// Builds a client retrieving its secret from AWS on first access.
+    pub(crate) fn lazy(client: Client, request: SecretRequest) -> AWSSecretClient {
+        AWSSecretClient {
+            client: Some(client),
+            request,
+            lazy: Some(Arc::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the version id of the loaded secret.
+    ///
+    /// # Returns
+    ///
+    /// The version id reported by AWS, or `None` if the client was not built from
+    /// AWS or its secret is not loaded yet
+    pub fn version_id(&self) -> Option<&str> {
+        match &self.lazy {
+            Some(lazy) => lazy.fetched.get()?.version_id.as_deref(),
+            None => self.version_id.as_deref(),
+        }
+    }
+
+    /// Returns the ARN of the loaded secret.
+    ///
+    /// The secret may be requested by its friendly name, in which case the ARN
+    /// tells which secret was actually retrieved, e.g. in multi-account setups.
+    ///
+    /// # Returns
+    ///
+    /// The ARN reported by AWS, or `None` if the client was not built from AWS
+    /// or its secret is not loaded yet
+    pub fn arn(&self) -> Option<&str> {
+        match &self.lazy {
+            Some(lazy) => lazy.fetched.get()?.arn.as_deref(),
+            None => self.arn.as_deref(),
+        }
+    }
+
+    /// Returns the id of the AWS request the secret was loaded with.
+    ///
+    /// The id identifies the request in AWS support tickets.
+    ///
+    /// # Returns
+    ///
+    /// The request id reported by AWS, or `None` if the client was not built
+    /// from AWS or its secret is not loaded yet
+    pub fn aws_request_id(&self) -> Option<&str> {
+        match &self.lazy {
+            Some(lazy) => lazy.fetched.get()?.request_id.as_deref(),
+            None => self.request_id.as_deref(),
+        }
+    }
+
+    /// Returns the metadata of the secret, including its tags.
+    ///
+    /// The metadata is retrieved with `DescribeSecret` by the builder, only when
+    /// enabled with `AWSSecretClientBuilder::with_metadata`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretMetadata)` - The metadata retrieved when building the client
+    /// * `Err(SecretsManagerError::NotSupported)` - If the metadata was not requested
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::AWSSecretClientBuilder;
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_metadata(true)
+    ///         .build()
+    ///         .await?;
+    ///
+    ///     let owner = client.describe()?.tags.get("Owner").cloned();
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn describe(&self) -> Result<SecretMetadata, SecretsManagerError> {
+        let Some(metadata) = &self.metadata else {
+            error!("secret metadata was not requested when building the client");
+            return Err(SecretsManagerError::NotSupported);
+        };
+
+        Ok(metadata.clone())
+    }
+
+    /// Returns the time the secrets were last loaded from AWS.
+    ///
+    /// This is the time the secret was retrieved when building the client, or
+    /// last reloaded with [`AWSSecretClient::reload`], which helps diagnosing
+    /// stale secrets. A client built with `WarmingStrategy::Lazy` reports the
+    /// time its secret was retrieved on first access.
+    ///
+    /// # Returns
+    ///
+    /// The time the cached secrets were loaded, or the time the client was built
+    /// if its secret is not loaded yet
+    pub fn loaded_at(&self) -> SystemTime {
+        match &self.lazy {
+            Some(lazy) => lazy
+                .fetched
+                .get()
+                .map_or(self.loaded_at, |fetched| fetched.loaded_at),
+            None => self.loaded_at,
+        }
+    }
+
+    /// Returns how long ago the secrets were last loaded from AWS.
+    ///
+    /// # Returns
+    ///
+    /// The time elapsed since [`AWSSecretClient::loaded_at`], or zero if the
+    /// system clock went backwards
+    pub fn age(&self) -> Duration {
+        self.loaded_at().elapsed().unwrap_or_default()
+    }
+
+    /// Re-fetches the secret from AWS and replaces the cached secrets.
+    ///
+    /// The same `GetSecretValue` request used by the builder is sent again, so
+    /// rotated values become visible without restarting the process. Reloading
+    /// from a background task requires sharing the client as
+    /// `Arc<Mutex<AWSSecretClient>>`, using an async-aware mutex such as
+    /// `tokio::sync::Mutex` since the lock is held across the request.
+    ///
+    /// The cached secrets are left untouched if the request fails.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the secrets were successfully reloaded
+    /// * `Err(SecretsManagerError::NotSupported)` - If the client was not built from AWS
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved or parsed
+    pub async fn reload(&mut self) -> Result<(), SecretsManagerError> {
+        let Some(client) = &self.client else {
+            error!("secret client was not built from aws and cannot be reloaded");
+            return Err(SecretsManagerError::NotSupported {});
+        };
+
+        let result = fetch_secret(client, &self.request).await;
+        self.emit(SecretEvent::from_result(&self.request.secret_id, &result));
+        let fetched = result?;
+
+        self.secrets = fetched.secrets;
+        self.version_id = fetched.version_id;
+        self.arn = fetched.arn;
+        self.request_id = fetched.request_id;
+        self.loaded_at = fetched.loaded_at;
+        self.lazy = None;
+        self.shared = RwLock::default();
+
+        Ok(())
+    }
+
+    /// Reads the environment variable overriding a secret, if overrides are allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// The value of `SECRETS_MANAGER_<KEY>`, or `None` if it is not set or the
+    /// client does not honor overrides
+    pub(crate) fn env_override(&self, key: &str) -> Option<SecretValue> {
+        if !self.request.allow_env_override {
+            return None;
+        }
+
+        let name = format!(
+            "{ENV_OVERRIDE_PREFIX}{}",
+            key.to_uppercase().replace(['-', '.'], "_")
+        );
+        let value = env::var(&name).ok()?;
+
+        warn!(
+            key = key,
+            variable = name,
+            "secret {} overridden by environment variable {}",
+            key,
+            name
+        );
+
+        Some(SecretValue::from(value))
+    }
+
+    /// Sends an event to the event sink, if any.
+    fn emit(&self, event: SecretEvent) {
+        if let Some(sink) = &self.event_sink {
+            sink.emit(event);
+        }
+    }
+
+    /// Returns the cached secrets, if loaded.
+    fn current(&self) -> Option<&Value> {
+        match &self.lazy {
+            Some(lazy) => lazy.fetched.get().map(|fetched| &fetched.secrets),
+            None => Some(&self.secrets),
+        }
+    }
+
+    /// Returns the cached secrets, starting to load them if the client was built lazily.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&Value)` - The cached secrets
+    /// * `Err(SecretsManagerError::NotYetLoaded)` - If the secrets are not loaded yet
+    pub(crate) fn loaded(&self) -> Result<&Value, SecretsManagerError> {
+        if let Some(secrets) = self.current() {
+            return Ok(secrets);
+        }
+
+        if let (Some(lazy), Some(client)) = (&self.lazy, &self.client) {
+            self.start_loading(lazy, client);
+        }
+
+        Err(SecretsManagerError::NotYetLoaded)
+    }
+
+    /// Retrieves the secret of a lazily built client on the current `tokio` runtime.
+    ///
+    /// Transient failures are retried with the retry policy of the builder. Only
+    /// one retrieval runs at a time; a failed retrieval is attempted again on
+    /// the next access.
+    fn start_loading(&self, lazy: &Arc<LazySecrets>, client: &Client) {
+        if lazy.loading.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let Ok(handle) = Handle::try_current() else {
+            warn!("secret cannot be loaded lazily outside of a tokio runtime");
+            lazy.loading.store(false, Ordering::Release);
+            return;
+        };
+
+        let lazy = lazy.clone();
+        let client = client.clone();
+        let request = self.request.clone();
+        let event_sink = self.event_sink.clone();
+        let span = info_span!("lazy_load", secret_id = %request.secret_id);
+
+        handle.spawn(
+            async move {
+                let result = fetch_with_retry(&client, &request).await;
+                if let Some(sink) = &event_sink {
+                    sink.emit(SecretEvent::from_result(&request.secret_id, &result));
+                }
+
+                match result {
+                    Ok(fetched) => {
+                        debug!("secret retrieved from aws");
+                        let _ = lazy.fetched.set(fetched);
+                    }
+                    Err(err) => warn!(error = err.to_string(), "failure to load secret lazily"),
+                }
+
+                lazy.loading.store(false, Ordering::Release);
+            }
+            .instrument(span),
+        );
+    }
+}
+
+impl Default for AWSSecretClient {
+    fn default() -> AWSSecretClient {
+        AWSSecretClient {
+            secrets: Value::default(),
+            client: None,
+            request: SecretRequest::default(),
+            version_id: None,
+            arn: None,
+            request_id: None,
+            loaded_at: SystemTime::now(),
+            lazy: None,
+            metadata: None,
+            shared: RwLock::default(),
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            event_sink: None,
+        }
+    }
+}
+
+impl Clone for AWSSecretClient {
+    fn clone(&self) -> AWSSecretClient {
+        let shared = self
+            .shared
+            .read()
+            .map(|shared| shared.clone())
+            .unwrap_or_default();
+
+        AWSSecretClient {
+            secrets: self.secrets.clone(),
+            client: self.client.clone(),
+            request: self.request.clone(),
+            version_id: self.version_id.clone(),
+            arn: self.arn.clone(),
+            request_id: self.request_id.clone(),
+            loaded_at: self.loaded_at,
+            lazy: self.lazy.clone(),
+            metadata: self.metadata.clone(),
+            shared: RwLock::new(shared),
+            #[cfg(feature = "metrics")]
+            metrics: self.metrics.clone(),
+            event_sink: self.event_sink.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for AWSSecretClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys = self
+            .current()
+            .and_then(Value::as_object)
+            .map(|secrets| secrets.keys().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        f.debug_struct("AWSSecretClient")
+            .field("secret_id", &self.request.secret_id)
+            .field("version_id", &self.version_id())
+            .field("arn", &self.arn())
+            .field("loaded_at", &self.loaded_at())
+            .field("keys", &keys)
+            .finish()
+    }
+}
+
+/// Shows the metadata of the client, e.g. in startup logs, but never a secret value.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::AWSSecretClient;
+/// use serde_json::json;
+///
+/// let client = AWSSecretClient::try_from(json!({ "user": "admin", "password": "secret" })).unwrap();
+/// let display = client.to_string();
+///
+/// assert!(display.starts_with(r#"AWSSecretClient { secret_id: "", key_count: 2, loaded_at: ""#));
+/// assert!(!display.contains("admin"));
+/// ```
+impl fmt::Display for AWSSecretClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key_count = self
+            .current()
+            .and_then(Value::as_object)
+            .map_or(0, |secrets| secrets.len());
+        let loaded_at = DateTime::from(self.loaded_at())
+            .fmt(DateTimeFormat::DateTime)
+            .map_err(|_| fmt::Error)?;
+
+        write!(
+            f,
+            "AWSSecretClient {{ secret_id: {:?}, key_count: {}, loaded_at: {:?} }}",
+            self.request.secret_id, key_count, loaded_at
+        )
+    }
+}
+
+/// Wraps an in-memory secret JSON object, e.g. for tests not talking to AWS.
+///
+/// The resulting client is not built from AWS, so it cannot be reloaded.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{AWSSecretClient, SecretClient};
+/// use serde_json::json;
+/// use std::collections::HashMap;
+///
+/// let client = AWSSecretClient::try_from(json!({ "password": "secret" })).unwrap();
+/// assert_eq!(&*client.get_by_key("password").unwrap(), "secret");
+///
+/// let client = AWSSecretClient::try_from(r#"{ "password": "secret" }"#).unwrap();
+/// assert_eq!(&*client.get_by_key("password").unwrap(), "secret");
+///
+/// let secrets = HashMap::from([("password".to_string(), "secret".to_string())]);
+/// let client = AWSSecretClient::try_from(secrets).unwrap();
+/// assert_eq!(&*client.get_by_key("password").unwrap(), "secret");
+///
+/// assert!(AWSSecretClient::try_from(json!(["password"])).is_err());
+/// ```
+impl TryFrom<Value> for AWSSecretClient {
+    type Error = SecretsManagerError;
+
+    /// Wraps the secret JSON value, which must be an object.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AWSSecretClient)` - A client serving the values of the object
+    /// * `Err(SecretsManagerError::DeserializationError)` - If the value is not an object
+    fn try_from(secrets: Value) -> Result<AWSSecretClient, SecretsManagerError> {
+        if !secrets.is_object() {
+            error!("secret value is not a json object");
+            return Err(SecretsManagerError::DeserializationError(
+                "secret value is not a json object".to_string(),
+            ));
+        }
+
+        Ok(AWSSecretClient {
+            secrets,
+            ..Default::default()
+        })
+    }
+}
+
+impl TryFrom<&str> for AWSSecretClient {
+    type Error = SecretsManagerError;
+
+    /// Parses the secret JSON string, which must hold an object.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AWSSecretClient)` - A client serving the values of the object
+    /// * `Err(SecretsManagerError::DeserializationError)` - If the string is not a JSON object
+    fn try_from(secrets: &str) -> Result<AWSSecretClient, SecretsManagerError> {
+        match serde_json::from_str::<Value>(secrets) {
+            Err(err) => {
+                error!(error = err.to_string(), "error mapping secrets");
+                Err(SecretsManagerError::DeserializationError(err.to_string()))
+            }
+            Ok(secrets) => AWSSecretClient::try_from(secrets),
+        }
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for AWSSecretClient {
+    type Error = SecretsManagerError;
+
+    /// Wraps the key-value pairs as a secret JSON object of strings.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AWSSecretClient)` - A client serving the given values
+    fn try_from(secrets: HashMap<String, String>) -> Result<AWSSecretClient, SecretsManagerError> {
+        AWSSecretClient::try_from(Value::Object(
+            secrets
+                .into_iter()
+                .map(|(key, value)| (key, Value::String(value)))
+                .collect(),
+        ))
+    }
 }
 
 #[cfg_attr(test, automock)]
 #[cfg_attr(feature = "mocks", automock)]
+#[async_trait]
 impl SecretClient for AWSSecretClient {
     /// Retrieves a secret from the cached secrets by its key.
     ///
-    /// If the key starts with '!', the prefix is removed before lookup.
+    /// The key is first matched verbatim against the top-level keys; if no string value
+    /// is found, it is treated as a dot-separated path into nested objects, so
+    /// `db.password` resolves `{"db": {"password": "..."}}`.
+    ///
+    /// The lookup runs in a `secret_lookup` span, a child of the caller's current
+    /// span, recording the key and whether it was `found`, so that lookups are
+    /// correlated with the surrounding request trace.
+    ///
+    /// For debugging and local development, the cached value can be overridden by
+    /// the `SECRETS_MANAGER_<KEY>` environment variable, where `<KEY>` is the key
+    /// uppercased with dashes and dots replaced by underscores. Overrides are only
+    /// honored by clients built while `SECRETS_MANAGER_ALLOW_ENV_OVERRIDE` was set
+    /// to `true`, and a warning is logged whenever one is served. Overridden values
+    /// are served by every accessor, except `list_keys` which only lists the keys
+    /// of the cached secrets, and are recorded in the span and metrics as found.
     ///
     /// # Arguments
     ///
@@ -38,17 +649,564 @@ impl SecretClient for AWSSecretClient {
     ///
     /// # Returns
     ///
-    /// * `Ok(String)` - The secret value as a string
+    /// * `Ok(SecretValue)` - The secret value
     /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
-    fn get_by_key(&self, key: &str) -> Result<String, SecretsManagerError> {
-        let key = key.strip_prefix("!").unwrap_or_default();
-        let value = self.secrets[key].clone();
+    /// * `Err(SecretsManagerError::NotYetLoaded)` - If the client was built lazily
+    ///   and its secret is still being retrieved
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use secrets_manager::{AWSSecretClientBuilder, SecretClient};
+    ///
+    /// // Run with `SECRETS_MANAGER_ALLOW_ENV_OVERRIDE=true SECRETS_MANAGER_DB_PASSWORD=local`
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string()).build().await?;
+    ///     assert_eq!(&*client.get_by_key("db-password")?, "local");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let key = self.request.key_prefix.strip(key);
+        let _span = info_span!("get_by_key", key = %key).entered();
+
+        let secret = {
+            let span = debug_span!(
+                parent: &Span::current(),
+                "secret_lookup",
+                key = %key,
+                found = field::Empty
+            );
+            let _lookup = span.enter();
 
-        let Value::String(secret) = value else {
-            error!(key = key, "secret {} was not found", key);
-            return Err(SecretsManagerError::SecretNotFound {});
+            let secret = match self.env_override(key) {
+                Some(secret) => Ok(secret),
+                None => self
+                    .loaded()
+                    .and_then(|secrets| lookup(secrets, key, BACKEND)),
+            };
+            span.record("found", secret.is_ok());
+            secret
         };
 
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_access(key, secret.is_ok());
+        }
+
+        self.emit(match &secret {
+            Ok(_) => SecretEvent::new(SecretEventKind::CacheHit, key),
+            Err(_) => SecretEvent::from_result(key, &secret),
+        });
+
+        let secret = secret?;
+        debug!("secret served from cache");
+
         Ok(secret)
     }
+
+    /// Retrieves a secret from the cached secrets as a shared string.
+    ///
+    /// The shared string is created on first access and reused afterwards, so
+    /// repeated calls only clone the `Arc`. Clients honoring environment overrides
+    /// do not reuse shared strings, so that a changed override is always served.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Arc<str>)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    fn get_arc_by_key(&self, key: &str) -> Result<Arc<str>, SecretsManagerError> {
+        if self.request.allow_env_override {
+            return Ok(Arc::from(&*self.get_by_key(key)?));
+        }
+
+        let cached = self.shared.read().ok().and_then(|s| s.get(key).cloned());
+
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_cache_lookup(cached.is_some());
+        }
+
+        if let Some(secret) = cached {
+            return Ok(secret);
+        }
+
+        let secret: Arc<str> = Arc::from(&*self.get_by_key(key)?);
+
+        if let Ok(mut shared) = self.shared.write() {
+            shared.insert(key.to_string(), secret.clone());
+        }
+
+        Ok(secret)
+    }
+
+    /// Retrieves a secret from the cached secrets as a JSON object.
+    ///
+    /// Nested objects of the secret JSON are returned as they are, and string
+    /// values holding a JSON object are parsed. Dot-separated keys are resolved
+    /// as by `get_by_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{AWSSecretClient, SecretClient};
+    /// use serde_json::json;
+    ///
+    /// let client = AWSSecretClient::try_from(json!({
+    ///     "endpoints": { "primary": "db-1.internal", "replica": "db-2.internal" }
+    /// }))
+    /// .unwrap();
+    ///
+    /// let endpoints = client.get_json_object_by_key("endpoints").unwrap();
+    /// assert_eq!(endpoints["replica"], "db-2.internal");
+    /// ```
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        let key = self.request.key_prefix.strip(key);
+
+        if let Some(secret) = self.env_override(key) {
+            return json_object(key, Value::String(secret.into_string()));
+        }
+
+        json_object(key, lookup_value(self.loaded()?, key, BACKEND)?.clone())
+    }
+
+    /// Retrieves a secret from the cached secrets as a JSON array.
+    ///
+    /// Nested arrays of the secret JSON are returned as they are, and string
+    /// values holding a JSON array are parsed. Dot-separated keys are resolved
+    /// as by `get_by_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{AWSSecretClient, SecretClient, errors::SecretsManagerError};
+    /// use serde_json::json;
+    ///
+    /// let client = AWSSecretClient::try_from(json!({
+    ///     "allowed_ips": ["1.2.3.4", "5.6.7.8"],
+    ///     "api-key": "value"
+    /// }))
+    /// .unwrap();
+    ///
+    /// let allowed_ips = client.get_json_array_by_key("allowed_ips").unwrap();
+    /// assert_eq!(allowed_ips, [json!("1.2.3.4"), json!("5.6.7.8")]);
+    /// assert_eq!(
+    ///     client.get_json_array_by_key("api-key"),
+    ///     Err(SecretsManagerError::TypeMismatch {
+    ///         key: "api-key".to_string(),
+    ///         expected: "array".into(),
+    ///     })
+    /// );
+    /// ```
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        let key = self.request.key_prefix.strip(key);
+
+        if let Some(secret) = self.env_override(key) {
+            return json_array(key, Value::String(secret.into_string()));
+        }
+
+        json_array(key, lookup_value(self.loaded()?, key, BACKEND)?.clone())
+    }
+
+    /// Checks whether the cached secrets hold a string value for the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is present, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        let key = self.request.key_prefix.strip(key);
+
+        self.env_override(key).is_some()
+            || self
+                .loaded()
+                .is_ok_and(|secrets| resolve(secrets, key).is_some())
+    }
+
+    /// Lists the top-level keys of the cached secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys of the cached secret JSON object
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        let Value::Object(secrets) = self.loaded()? else {
+            return Ok(Vec::new());
+        };
+
+        Ok(secrets.keys().cloned().collect())
+    }
+
+    /// Retrieves all top-level string secrets of the cached secrets.
+    ///
+    /// The values of the keys overridden by environment variables are replaced by
+    /// the overrides.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - Every top-level string value keyed by its key
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        let Value::Object(secrets) = self.loaded()? else {
+            return Ok(HashMap::new());
+        };
+
+        Ok(secrets
+            .iter()
+            .filter_map(|(key, value)| match self.env_override(key) {
+                Some(secret) => Some((key.clone(), secret)),
+                None => value
+                    .as_str()
+                    .map(|v| (key.clone(), SecretValue::from(v.to_string()))),
+            })
+            .collect())
+    }
+
+    /// Lists the top-level keys of the cached secrets starting with the given prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix the returned keys start with
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The matching keys of the cached secret JSON object
+    fn keys_with_prefix(&self, prefix: &str) -> Result<Vec<String>, SecretsManagerError> {
+        let Value::Object(secrets) = self.loaded()? else {
+            return Ok(Vec::new());
+        };
+
+        Ok(secrets
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    /// Retrieves a secret by its key with a fresh `GetSecretValue` call.
+    ///
+    /// This bypasses the in-memory cache so callers always observe the current
+    /// value stored in AWS. If the client was not created by the builder it has
+    /// no SDK client to call, and the cached secrets are used instead. A secret
+    /// overridden by an environment variable is served without calling AWS.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the request failed or the secret is not found
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let key = self.request.key_prefix.strip(key);
+        let span = info_span!("get_by_key_async", key = %key);
+
+        async {
+            if let Some(secret) = self.env_override(key) {
+                return Ok(secret);
+            }
+
+            let Some(client) = &self.client else {
+                return lookup(self.loaded()?, key, BACKEND);
+            };
+
+            let fetched = fetch_secret(client, &self.request).await?;
+
+            lookup(&fetched.secrets, key, BACKEND)
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+#[async_trait]
+impl HealthCheckableSecretClient for AWSSecretClient {
+    /// Checks whether AWS Secrets Manager can be reached by describing the secret.
+    ///
+    /// A `DescribeSecret` call is sent for the configured secret, which reads no
+    /// secret value. The check fails if AWS does not answer within the health
+    /// check timeout, see `AWSSecretClientBuilder::with_health_check_timeout`.
+    /// A client not created by the builder has no backend to reach, and is
+    /// always available.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret could be described in time, `false` otherwise
+    async fn is_available(&self) -> bool {
+        let Some(client) = &self.client else {
+            return true;
+        };
+
+        let timeout = self
+            .request
+            .health_check_timeout
+            .unwrap_or(DEFAULT_HEALTH_CHECK_TIMEOUT);
+
+        match tokio::time::timeout(timeout, describe_secret(client, &self.request.secret_id)).await
+        {
+            Err(_) => {
+                warn!(
+                    secret_id = self.request.secret_id,
+                    "health check of secret {} timed out", self.request.secret_id
+                );
+                false
+            }
+            Ok(Err(err)) => {
+                warn!(
+                    error = err.to_string(),
+                    secret_id = self.request.secret_id,
+                    "health check of secret {} failed",
+                    self.request.secret_id
+                );
+                false
+            }
+            Ok(Ok(_)) => true,
+        }
+    }
+}
+
+impl BinarySecretClient for AWSSecretClient {
+    /// Retrieves a binary secret from the cached secrets by its key.
+    ///
+    /// Binary secrets are stored base64-encoded in the cached secrets, so the
+    /// value is decoded before being returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The decoded secret bytes
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    /// * `Err(SecretsManagerError::DeserializationError)` - If the value is not valid base64
+    fn get_binary_by_key(&self, key: &str) -> Result<Vec<u8>, SecretsManagerError> {
+        let key = self.request.key_prefix.strip(key);
+        let encoded = lookup(self.loaded()?, key, BACKEND)?;
+
+        STANDARD.decode(&*encoded).map_err(|err| {
+            error!(error = err.to_string(), "error decoding binary secret");
+            SecretsManagerError::DeserializationError(err.to_string())
+        })
+    }
+}
+
+/// Retrieves a secret from AWS Secrets Manager and parses its JSON content.
+///
+/// If the secret holds a binary blob instead of a string, the blob is stored
+/// base64-encoded under the secret id so it can be read with either
+/// `get_by_key` or `get_binary_by_key`.
+///
+/// # Arguments
+///
+/// * `client` - The Secrets Manager client used to send the request
+/// * `request` - The secret, and optionally its version, to retrieve
+///
+/// # Returns
+///
+/// * `Ok(FetchedSecret)` - The parsed secret JSON and its metadata
+/// * `Err(SecretsManagerError)` - If any step failed
+pub(crate) async fn fetch_secret(
+    client: &Client,
+    request: &SecretRequest,
+) -> Result<FetchedSecret, SecretsManagerError> {
+    let id = &request.secret_id;
+
+    let output = match client
+        .get_secret_value()
+        .secret_id(id)
+        .set_version_id(request.version_id.clone())
+        .set_version_stage(request.version_stage.clone())
+        .send()
+        .await
+    {
+        Err(err) => {
+            error!(
+                error = DisplayErrorContext(&err).to_string(),
+                request_id = err.request_id(),
+                "failure send request to secret manager"
+            );
+            Err(SecretsManagerError::from_aws_error(&err))
+        }
+        Ok(s) => Ok(s),
+    }?;
+
+    let Some(string) = output.secret_string() else {
+        let Some(binary) = output.secret_binary() else {
+            error!("secret was not found");
+            return Err(SecretsManagerError::AwsSecretWasNotFound {
+                secret_id: id.to_string(),
+            });
+        };
+
+        let mut secrets = serde_json::Map::new();
+        secrets.insert(
+            id.to_string(),
+            Value::String(STANDARD.encode(binary.as_ref())),
+        );
+
+        return Ok(FetchedSecret {
+            secrets: Value::Object(secrets),
+            version_id: output.version_id().map(str::to_string),
+            arn: output.arn().map(str::to_string),
+            request_id: output.request_id().map(str::to_string),
+            loaded_at: SystemTime::now(),
+        });
+    };
+
+    match serde_json::from_str(string) {
+        Err(err) => {
+            error!(error = err.to_string(), "error mapping secrets");
+            Err(SecretsManagerError::internal(err))
+        }
+        Ok(v) => Ok(FetchedSecret {
+            secrets: match request.key_transform {
+                Some(transform) => transform_keys(v, transform),
+                None => v,
+            },
+            version_id: output.version_id().map(str::to_string),
+            arn: output.arn().map(str::to_string),
+            request_id: output.request_id().map(str::to_string),
+            loaded_at: SystemTime::now(),
+        }),
+    }
+}
+
+/// Retrieves a secret, retrying transient failures with exponential backoff.
+///
+/// # Arguments
+///
+/// * `client` - The Secrets Manager client used to send the request
+/// * `request` - The secret to retrieve, along with the retry policy; at least
+///   one attempt is always made
+///
+/// # Returns
+///
+/// * `Ok(FetchedSecret)` - The parsed secret JSON and its metadata
+/// * `Err(SecretsManagerError)` - The error of the last attempt
+pub(crate) async fn fetch_with_retry(
+    client: &Client,
+    request: &SecretRequest,
+) -> Result<FetchedSecret, SecretsManagerError> {
+    let mut attempt = 1;
+
+    loop {
+        match fetch_secret(client, request).await {
+            Err(err) if err.is_retryable() && attempt < request.max_attempts => {
+                let delay = request
+                    .base_delay
+                    .saturating_mul(2u32.saturating_pow(attempt - 1));
+                let jitter = rand::rng().random_range(Duration::ZERO..=delay / 2);
+
+                warn!(
+                    error = err.to_string(),
+                    attempt = attempt,
+                    "retrying request to secret manager"
+                );
+
+                tokio::time::sleep(delay + jitter).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Retrieves the metadata of a secret from AWS.
+///
+/// # Arguments
+///
+/// * `client` - The Secrets Manager client used to send the request
+/// * `secret_id` - The name/id of the secret
+///
+/// # Returns
+///
+/// * `Ok(SecretMetadata)` - The name, ARN, creation date and tags of the secret
+/// * `Err(SecretsManagerError)` - If the request failed
+pub(crate) async fn describe_secret(
+    client: &Client,
+    secret_id: &str,
+) -> Result<SecretMetadata, SecretsManagerError> {
+    let output = match client.describe_secret().secret_id(secret_id).send().await {
+        Err(err) => {
+            error!(
+                error = DisplayErrorContext(&err).to_string(),
+                "failure send request to secret manager"
+            );
+            Err(SecretsManagerError::from_aws_error(&err))
+        }
+        Ok(o) => Ok(o),
+    }?;
+
+    Ok(SecretMetadata {
+        name: output.name().unwrap_or_default().to_string(),
+        arn: output.arn().unwrap_or_default().to_string(),
+        created_date: output
+            .created_date()
+            .and_then(|date| SystemTime::try_from(*date).ok()),
+        tags: output
+            .tags()
+            .iter()
+            .filter_map(|tag| Some((tag.key()?.to_string(), tag.value()?.to_string())))
+            .collect(),
+    })
+}
+
+/// Returns whether environment variables may override secrets.
+///
+/// # Returns
+///
+/// `true` if `SECRETS_MANAGER_ALLOW_ENV_OVERRIDE` is set to `true`
+pub(crate) fn env_override_allowed() -> bool {
+    env::var(ALLOW_ENV_OVERRIDE_VAR).is_ok_and(|allowed| allowed == "true")
+}
+
+/// Applies a transform to the top-level keys of a secret JSON object.
+///
+/// # Arguments
+///
+/// * `secrets` - The parsed secret JSON
+/// * `transform` - The transform of a key
+///
+/// # Returns
+///
+/// The object with transformed keys, or the value unchanged if it is not an object
+fn transform_keys(secrets: Value, transform: fn(&str) -> String) -> Value {
+    let Value::Object(secrets) = secrets else {
+        return secrets;
+    };
+
+    Value::Object(
+        secrets
+            .into_iter()
+            .map(|(key, value)| (transform(&key), value))
+            .collect(),
+    )
 }
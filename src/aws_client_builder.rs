@@ -7,15 +7,102 @@
 //! This module provides the `AWSSecretClientBuilder` for constructing instances of
 //! `AWSSecretClient` with appropriate configuration.
 
-use crate::{AWSSecretClient, errors::SecretsManagerError};
-use aws_config::BehaviorVersion;
+#[cfg(feature = "metrics")]
+use crate::access_metrics::{AccessMetrics, DEFAULT_PREFIX, record_build_duration};
+#[cfg(feature = "opentelemetry")]
+use crate::trace_context::TraceContextInterceptor;
+use crate::{
+    AWSSecretClient, SecretEvent,
+    aws_client::{SecretRequest, describe_secret, env_override_allowed, fetch_with_retry},
+    client::join_all,
+    errors::SecretsManagerError,
+    events::EventSink,
+    role_credentials::{Role, RoleCredentialsProvider},
+};
+use aws_config::{
+    BehaviorVersion, Region, SdkConfig, profile::ProfileFileCredentialsProvider,
+    timeout::TimeoutConfig,
+};
+
 use aws_sdk_secretsmanager as secretsmanager;
 #[cfg(test)]
 use mockall::*;
 #[cfg(feature = "mocks")]
 use mockall::*;
-use secretsmanager::Client;
-use tracing::error;
+use secretsmanager::{
+    Client,
+    config::{ProvideCredentials, SharedCredentialsProvider},
+};
+use std::{collections::HashMap, path::Path, sync::Arc, time::Duration};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{Instrument, debug, info_span};
+
+/// Controls when the secret is retrieved from AWS.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WarmingStrategy {
+    /// The secret is retrieved by `build`, which fails if it cannot be retrieved
+    #[default]
+    EagerFail,
+    /// The secret is retrieved in the background on the first access to the client
+    ///
+    /// `build` does not call AWS, and the client returns
+    /// `SecretsManagerError::NotYetLoaded` until the secret has been retrieved.
+    /// Retrieving the secret requires accessing the client from within a `tokio`
+    /// runtime.
+    Lazy,
+}
+
+/// Controls whether a leading sigil is removed from the keys given to `AWSSecretClient`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyPrefix {
+    /// Keys are looked up exactly as given
+    #[default]
+    None,
+    /// The character is removed from the start of the keys before they are looked up
+    ///
+    /// Keys that do not start with the character are looked up unchanged.
+    Strip(char),
+}
+
+impl KeyPrefix {
+    /// Returns the key with the configured sigil removed, if any.
+    pub(crate) fn strip(self, key: &str) -> &str {
+        match self {
+            KeyPrefix::Strip(sigil) => key.strip_prefix(sigil).unwrap_or(key),
+            KeyPrefix::None => key,
+        }
+    }
+}
+
+/// Pre-built transforms of the secret keys, for `AWSSecretClientBuilder::with_key_transform`.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::KeyTransform;
+///
+/// assert_eq!(KeyTransform::kebab_to_snake("db-password"), "db_password");
+/// assert_eq!(KeyTransform::upper_to_lower("DB_PASSWORD"), "db_password");
+/// assert_eq!(KeyTransform::identity("db-password"), "db-password");
+/// ```
+pub struct KeyTransform;
+
+impl KeyTransform {
+    /// Replaces the dashes of a kebab-case key with underscores, e.g. `db-password` to `db_password`.
+    pub fn kebab_to_snake(key: &str) -> String {
+        key.replace('-', "_")
+    }
+
+    /// Lowercases an uppercase key, e.g. `DB_PASSWORD` to `db_password`.
+    pub fn upper_to_lower(key: &str) -> String {
+        key.to_lowercase()
+    }
+
+    /// Leaves the key unchanged.
+    pub fn identity(key: &str) -> String {
+        key.to_string()
+    }
+}
 
 /// Builder for AWS Secret Client instances.
 ///
@@ -25,6 +112,49 @@ use tracing::error;
 pub struct AWSSecretClientBuilder {
     /// The secret key to retrieve from AWS Secrets Manager
     secret_key: String,
+    /// The unique identifier of the secret version to retrieve
+    version_id: Option<String>,
+    /// The staging label of the secret version to retrieve
+    version_stage: Option<String>,
+    /// The maximum number of attempts made to retrieve the secret
+    max_attempts: u32,
+    /// The delay before the first retry, doubled on every subsequent retry
+    base_delay: Duration,
+    /// The Secrets Manager endpoint URL overriding the default one
+    endpoint_url: Option<String>,
+    /// The AWS region overriding the ambient one
+    region: Option<String>,
+    /// A pre-configured Secrets Manager client used instead of loading the AWS configuration
+    client: Option<Client>,
+    /// An already-loaded AWS configuration used instead of loading the default one
+    shared_config: Option<Arc<SdkConfig>>,
+    /// The AWS SDK behavior version the configuration is loaded with, the latest if not set
+    behavior_version: Option<BehaviorVersion>,
+    /// When the secret is retrieved from AWS
+    warming_strategy: WarmingStrategy,
+    /// The transform applied to the keys of the retrieved secret
+    key_transform: Option<fn(&str) -> String>,
+    /// The sigil removed from the keys given to the built client
+    key_prefix: KeyPrefix,
+    /// Whether the metadata of the secret is retrieved along with its value
+    metadata: bool,
+    /// The sender the events of the built client are sent to, if any
+    event_sink: Option<UnboundedSender<SecretEvent>>,
+    /// The maximum duration of a Secrets Manager operation
+    timeout: Option<Duration>,
+    /// How long `is_available` waits for AWS
+    health_check_timeout: Option<Duration>,
+    /// The provider of the credentials replacing the default credential chain
+    credentials_provider: Option<SharedCredentialsProvider>,
+    /// Whether the built client records metrics with the `metrics` crate
+    #[cfg(feature = "metrics")]
+    metrics: bool,
+    /// The prefix of the recorded metric names, `secrets_manager` if not set
+    #[cfg(feature = "metrics")]
+    metrics_prefix: Option<String>,
+    /// Whether the trace context of the current span is sent along with AWS requests
+    #[cfg(feature = "opentelemetry")]
+    trace_propagation: bool,
 }
 
 #[cfg_attr(test, automock)]
@@ -39,8 +169,600 @@ impl AWSSecretClientBuilder {
     /// # Returns
     ///
     /// A new instance of `AWSSecretClientBuilder`
+    #[must_use = "call `.build()` to create the client"]
     pub fn new(secret_key: String) -> AWSSecretClientBuilder {
-        AWSSecretClientBuilder { secret_key }
+        AWSSecretClientBuilder {
+            secret_key,
+            ..Default::default()
+        }
+    }
+
+    /// Requests a specific version of the secret by its unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_id` - The unique identifier of the secret version
+    ///
+    /// # Returns
+    ///
+    /// The builder with the version id configured
+    pub fn version_id(mut self, version_id: String) -> AWSSecretClientBuilder {
+        self.version_id = Some(version_id);
+        self
+    }
+
+    /// Requests the version of the secret attached to a staging label.
+    ///
+    /// This allows falling back to `AWSPREVIOUS` during a rotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `version_stage` - The staging label of the secret version, e.g. `AWSCURRENT`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the version stage configured
+    pub fn version_stage(mut self, version_stage: String) -> AWSSecretClientBuilder {
+        self.version_stage = Some(version_stage);
+        self
+    }
+
+    /// Retries transient failures when retrieving the secret.
+    ///
+    /// Retryable errors, see [`SecretsManagerError::is_retryable`], are retried
+    /// with exponential backoff:
+    /// the n-th retry waits `base_delay * 2^(n-1)` plus a random jitter of up
+    /// to 50% of that delay. Without this method the secret is requested once.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - The maximum number of attempts, including the first one
+    /// * `base_delay` - The delay before the first retry
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry policy configured
+    pub fn with_retry(mut self, max_attempts: u32, base_delay: Duration) -> AWSSecretClientBuilder {
+        self.max_attempts = max_attempts;
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Overrides the Secrets Manager endpoint URL.
+    ///
+    /// This allows running against LocalStack or a Moto server without real
+    /// AWS credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The endpoint URL, e.g. `http://localhost:4566`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the endpoint URL configured
+    pub fn with_endpoint_url(mut self, url: &str) -> AWSSecretClientBuilder {
+        self.endpoint_url = Some(url.to_string());
+        self
+    }
+
+    /// Overrides the AWS region the secret is retrieved from.
+    ///
+    /// Without this method the region is resolved from the ambient environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The AWS region, e.g. `us-east-1`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the region configured
+    pub fn with_region(mut self, region: &str) -> AWSSecretClientBuilder {
+        self.region = Some(region.to_string());
+        self
+    }
+
+    /// Uses an already-built Secrets Manager client.
+    ///
+    /// When set, the AWS configuration is not loaded and the endpoint URL and
+    /// region overrides are ignored, since the client carries its own configuration.
+    /// This allows injecting a client backed by a Smithy mock in tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The Secrets Manager client used to retrieve the secret
+    ///
+    /// # Returns
+    ///
+    /// The builder with the SDK client configured
+    pub fn with_sdk_client(mut self, client: Client) -> AWSSecretClientBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Uses an already-loaded AWS configuration instead of loading the default one.
+    ///
+    /// Loading the AWS configuration resolves the region and credentials from the
+    /// environment, which is slow when many clients are built, e.g. in tests or
+    /// during a cold start retrieving many secrets. Sharing one `SdkConfig` across
+    /// builders loads it only once. The endpoint URL, region, timeout, credentials
+    /// and role overrides are still applied on top of the shared configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The AWS SDK configuration shared by several builders
+    ///
+    /// # Returns
+    ///
+    /// The builder with the shared configuration configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aws_config::BehaviorVersion;
+    /// use secrets_manager::AWSSecretClientBuilder;
+    /// use std::sync::Arc;
+    ///
+    /// async fn example() {
+    ///     let config = Arc::new(aws_config::load_defaults(BehaviorVersion::latest()).await);
+    ///
+    ///     let db = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_shared_config(config.clone());
+    ///     let redis = AWSSecretClientBuilder::new("infra/redis".to_string())
+    ///         .with_shared_config(config);
+    ///
+    ///     let (db, redis) = tokio::join!(db.build(), redis.build());
+    /// }
+    /// ```
+    pub fn with_shared_config(mut self, config: Arc<SdkConfig>) -> AWSSecretClientBuilder {
+        self.shared_config = Some(config);
+        self
+    }
+
+    /// Pins the AWS SDK behavior version the configuration is loaded with.
+    ///
+    /// Behavior versions change SDK defaults such as the retry strategy and the
+    /// endpoint resolution. Without this method the latest behavior version is
+    /// used, which may change on SDK upgrades. The behavior version is ignored
+    /// when a shared configuration or an SDK client is provided, since they
+    /// carry their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The behavior version, e.g. `BehaviorVersion::v2025_01_17()`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the behavior version configured
+    pub fn with_behavior_version(mut self, version: BehaviorVersion) -> AWSSecretClientBuilder {
+        self.behavior_version = Some(version);
+        self
+    }
+
+    /// Controls when the secret is retrieved from AWS.
+    ///
+    /// Without this method the secret is retrieved by `build`
+    /// (`WarmingStrategy::EagerFail`), so that a service fails fast at startup
+    /// if its secret is missing. With `WarmingStrategy::Lazy`, the service starts
+    /// without calling AWS and the secret is retrieved on first access, with the
+    /// retry policy configured by [`AWSSecretClientBuilder::with_retry`].
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - When the secret is retrieved
+    ///
+    /// # Returns
+    ///
+    /// The builder with the warming strategy configured
+    pub fn with_warming_strategy(mut self, strategy: WarmingStrategy) -> AWSSecretClientBuilder {
+        self.warming_strategy = strategy;
+        self
+    }
+
+    /// Transforms the keys of the retrieved secret JSON object.
+    ///
+    /// The transform is applied to the top-level keys whenever the secret is
+    /// retrieved, so that the client is accessed with the transformed keys, e.g.
+    /// `get_by_key("db_password")` returns the `"db-password"` value with
+    /// `KeyTransform::kebab_to_snake`. Nested keys are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The transform of a key, e.g. one of `KeyTransform`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the key transform configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{AWSSecretClientBuilder, KeyTransform};
+    ///
+    /// let builder = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///     .with_key_transform(KeyTransform::kebab_to_snake);
+    /// ```
+    pub fn with_key_transform(mut self, transform: fn(&str) -> String) -> AWSSecretClientBuilder {
+        self.key_transform = Some(transform);
+        self
+    }
+
+    /// Removes a leading sigil from the keys given to the built client.
+    ///
+    /// Keys are looked up exactly as given by default. Applications written for
+    /// versions of this crate that always dropped a leading `!` can keep using
+    /// `get_by_key("!database-password")` with `KeyPrefix::Strip('!')`. Unlike
+    /// `PrefixStrippingSecretClient`, the sigil is removed by `AWSSecretClient`
+    /// itself, so no wrapper is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Whether and which sigil is removed
+    ///
+    /// # Returns
+    ///
+    /// The builder with the key prefix configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use secrets_manager::{AWSSecretClientBuilder, KeyPrefix, SecretClient};
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_key_prefix(KeyPrefix::Strip('!'))
+    ///         .build()
+    ///         .await?;
+    ///
+    ///     let password = client.get_by_key("!database-password")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_key_prefix(mut self, prefix: KeyPrefix) -> AWSSecretClientBuilder {
+        self.key_prefix = prefix;
+        self
+    }
+
+    /// Retrieves the metadata of the secret, including its tags, when building the client.
+    ///
+    /// The metadata is retrieved with an additional `DescribeSecret` request, sent
+    /// by `build` even with `WarmingStrategy::Lazy`, and is then available from
+    /// `AWSSecretClient::describe`. The caller needs the
+    /// `secretsmanager:DescribeSecret` permission.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the metadata is retrieved
+    ///
+    /// # Returns
+    ///
+    /// The builder with the metadata retrieval configured
+    pub fn with_metadata(mut self, enabled: bool) -> AWSSecretClientBuilder {
+        self.metadata = enabled;
+        self
+    }
+
+    /// Emits the lifecycle events of the built client to the given sender.
+    ///
+    /// Every retrieval of the secret from AWS, when building, loading lazily or
+    /// reloading, emits a `Fetched`, `NotFound` or `Error` event keyed by the
+    /// secret id. Every lookup by `get_by_key` emits a `CacheHit` event if the
+    /// key is served from the cached secrets, and a `NotFound` or `Error` event
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The sender the events are sent to
+    ///
+    /// # Returns
+    ///
+    /// The builder with the event sink configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{AWSSecretClientBuilder, SecretEventKind};
+    /// use tokio::sync::mpsc;
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let (sender, mut receiver) = mpsc::unbounded_channel();
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_event_sink(sender)
+    ///         .build()
+    ///         .await?;
+    ///
+    ///     assert_eq!(receiver.recv().await.unwrap().kind, SecretEventKind::Fetched);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_event_sink(
+        mut self,
+        sender: UnboundedSender<SecretEvent>,
+    ) -> AWSSecretClientBuilder {
+        self.event_sink = Some(sender);
+        self
+    }
+
+    /// Limits how long a Secrets Manager operation may take.
+    ///
+    /// The AWS SDK applies no operation timeout by default, so a slow API can
+    /// stall `build` indefinitely. When the timeout elapses the request fails with
+    /// `SecretsManagerError::Timeout`. The timeout is ignored when an SDK client
+    /// is provided through `with_sdk_client`.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The maximum duration of an operation, including SDK retries
+    ///
+    /// # Returns
+    ///
+    /// The builder with the timeout configured
+    pub fn with_timeout(mut self, duration: Duration) -> AWSSecretClientBuilder {
+        self.timeout = Some(duration);
+        self
+    }
+
+    /// Sets how long `is_available` waits for AWS before reporting it unavailable.
+    ///
+    /// Without this method the health check times out after 2 seconds, which
+    /// keeps liveness probes well within their own timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The maximum duration of a health check
+    ///
+    /// # Returns
+    ///
+    /// The builder with the health check timeout configured
+    pub fn with_health_check_timeout(mut self, duration: Duration) -> AWSSecretClientBuilder {
+        self.health_check_timeout = Some(duration);
+        self
+    }
+
+    /// Assumes an IAM role before retrieving the secret.
+    ///
+    /// This is required in cross-account scenarios where the ambient credentials
+    /// cannot read the secret directly. Temporary credentials for the role are
+    /// vended through STS using the credentials configured so far, e.g. with
+    /// `with_profile`, or the default credential chain otherwise.
+    ///
+    /// This is a shorthand for `with_credentials_provider` with a provider of the
+    /// assumed role, which replaces the configured credentials provider. The STS
+    /// endpoint of the region configured so far is used, the ambient one otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `role_arn` - The ARN of the IAM role to assume
+    /// * `session_name` - The session name identifying the assumed role session
+    ///
+    /// # Returns
+    ///
+    /// The builder with the role assumption configured
+    pub fn assume_role(mut self, role_arn: &str, session_name: &str) -> AWSSecretClientBuilder {
+        let role = Role::Assumed {
+            role_arn: role_arn.to_string(),
+            session_name: session_name.to_string(),
+            base: self.credentials_provider.take(),
+        };
+        let region = self.region.clone().map(Region::new);
+
+        self.with_credentials_provider(RoleCredentialsProvider::new(role, region))
+    }
+
+    /// Reads the credentials from a named profile of the AWS configuration files.
+    ///
+    /// Profiles of `~/.aws/config` and `~/.aws/credentials` are supported, including
+    /// AWS SSO profiles once `aws sso login` was run, which makes this the usual way
+    /// to authenticate during local development. The region is still resolved from
+    /// the ambient environment, or set with `with_region`. An IAM role configured
+    /// afterwards with `assume_role` is assumed with the profile credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile_name` - The name of the profile, e.g. `dev`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the profile configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use secrets_manager::AWSSecretClientBuilder;
+    ///
+    /// async fn example() {
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_profile("dev")
+    ///         .build()
+    ///         .await;
+    /// }
+    /// ```
+    pub fn with_profile(self, profile_name: &str) -> AWSSecretClientBuilder {
+        self.with_credentials_provider(
+            ProfileFileCredentialsProvider::builder()
+                .profile_name(profile_name)
+                .build(),
+        )
+    }
+
+    /// Replaces the default credential chain with the given credentials provider.
+    ///
+    /// Any provider of `aws_config` can be used, e.g. an `ImdsCredentialsProvider`,
+    /// as well as custom implementations. `with_profile` is a shorthand for a
+    /// `ProfileFileCredentialsProvider`, and `with_web_identity_token_file` a
+    /// shorthand for a provider of the role; the last one called wins. An IAM
+    /// role configured afterwards with `assume_role` is assumed with the
+    /// credentials of the provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider of the credentials
+    ///
+    /// # Returns
+    ///
+    /// The builder with the credentials provider configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use aws_config::imds::credentials::ImdsCredentialsProvider;
+    /// use secrets_manager::AWSSecretClientBuilder;
+    ///
+    /// async fn example() {
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_credentials_provider(ImdsCredentialsProvider::builder().build())
+    ///         .build()
+    ///         .await;
+    /// }
+    /// ```
+    pub fn with_credentials_provider<P: ProvideCredentials + 'static>(
+        mut self,
+        provider: P,
+    ) -> AWSSecretClientBuilder {
+        self.credentials_provider = Some(SharedCredentialsProvider::new(provider));
+        self
+    }
+
+    /// Assumes an IAM role with a web identity token file, as done on EKS.
+    ///
+    /// Pods using IAM roles for service accounts are given a token file which is
+    /// exchanged through STS for temporary credentials of the role. Unlike the
+    /// default credential chain, this does not require the `AWS_ROLE_ARN` and
+    /// `AWS_WEB_IDENTITY_TOKEN_FILE` environment variables to be set. The token
+    /// file is read again whenever the credentials are refreshed, so that tokens
+    /// rotated by the kubelet are picked up.
+    ///
+    /// This is a shorthand for `with_credentials_provider` with a provider of the
+    /// role, which replaces the configured credentials provider. The STS endpoint
+    /// of the region configured so far is used, the ambient one otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `role_arn` - The ARN of the IAM role to assume
+    /// * `token_file` - The path of the web identity token file
+    ///
+    /// # Returns
+    ///
+    /// The builder with the web identity configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use secrets_manager::AWSSecretClientBuilder;
+    /// use std::path::Path;
+    ///
+    /// async fn example() {
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_web_identity_token_file(
+    ///             "arn:aws:iam::123456789012:role/my-service",
+    ///             Path::new("/var/run/secrets/eks.amazonaws.com/serviceaccount/token"),
+    ///         )
+    ///         .build()
+    ///         .await;
+    /// }
+    /// ```
+    pub fn with_web_identity_token_file(
+        self,
+        role_arn: &str,
+        token_file: &Path,
+    ) -> AWSSecretClientBuilder {
+        let role = Role::WebIdentity {
+            role_arn: role_arn.to_string(),
+            token_file: token_file.to_path_buf(),
+        };
+        let region = self.region.clone().map(Region::new);
+
+        self.with_credentials_provider(RoleCredentialsProvider::new(role, region))
+    }
+
+    /// Enables recording of metrics with the `metrics` crate.
+    ///
+    /// When enabled, `build` records the `secrets_manager.build_duration_ms`
+    /// histogram and the built client increments the
+    /// `secrets_manager.get_by_key.total` and `secrets_manager.get_by_key.error`
+    /// counters, labelled with the accessed `key`, and updates the
+    /// `secrets_manager.cache_hit_ratio` gauge of the values shared by
+    /// `get_arc_by_key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether metrics are recorded
+    ///
+    /// # Returns
+    ///
+    /// The builder with metrics configured
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(mut self, enabled: bool) -> AWSSecretClientBuilder {
+        self.metrics = enabled;
+        self
+    }
+
+    /// Enables recording of metrics under a custom prefix.
+    ///
+    /// The metrics described in [`AWSSecretClientBuilder::with_metrics`] are
+    /// recorded with `prefix` in place of `secrets_manager`, e.g.
+    /// `payments.secrets.get_by_key.total`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of every metric name
+    ///
+    /// # Returns
+    ///
+    /// The builder with metrics configured
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics_prefix(mut self, prefix: &str) -> AWSSecretClientBuilder {
+        self.metrics = true;
+        self.metrics_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Propagates the OpenTelemetry trace context to Secrets Manager.
+    ///
+    /// When enabled, the requests sent to AWS carry the trace context of the
+    /// current `tracing` span, as produced by the globally installed propagator,
+    /// e.g. the W3C TraceContext `traceparent` header, so that AWS X-Ray or
+    /// Jaeger show Secrets Manager as a dependency of the traced request. The
+    /// span context is read through `tracing-opentelemetry`. Propagation is
+    /// ignored when an SDK client is provided through `with_sdk_client`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the trace context is propagated
+    ///
+    /// # Returns
+    ///
+    /// The builder with trace propagation configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use opentelemetry::{global, trace::TracerProvider};
+    /// use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider};
+    /// use secrets_manager::AWSSecretClientBuilder;
+    /// use tracing::Instrument;
+    /// use tracing_subscriber::{Registry, layer::SubscriberExt};
+    ///
+    /// async fn example() {
+    ///     // Send W3C TraceContext headers and export the spans of `tracing`
+    ///     global::set_text_map_propagator(TraceContextPropagator::new());
+    ///     let provider = SdkTracerProvider::builder().build();
+    ///     let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("app"));
+    ///     tracing::subscriber::set_global_default(Registry::default().with(layer)).unwrap();
+    ///
+    ///     // The request to AWS carries the context of the `handle_request` span
+    ///     let client = AWSSecretClientBuilder::new("infra/db".to_string())
+    ///         .with_trace_propagation(true)
+    ///         .build()
+    ///         .instrument(tracing::info_span!("handle_request"))
+    ///         .await;
+    /// }
+    /// ```
+    #[cfg(feature = "opentelemetry")]
+    pub fn with_trace_propagation(mut self, enabled: bool) -> AWSSecretClientBuilder {
+        self.trace_propagation = enabled;
+        self
     }
 
     /// Returns the secret ID to be used in AWS API calls.
@@ -52,12 +774,117 @@ impl AWSSecretClientBuilder {
         self.secret_key.to_string()
     }
 
+    /// Returns the request describing the secret and version to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// The configured `SecretRequest`
+    fn request(&self) -> SecretRequest {
+        SecretRequest {
+            secret_id: self.secret_id(),
+            version_id: self.version_id.clone(),
+            version_stage: self.version_stage.clone(),
+            key_transform: self.key_transform,
+            key_prefix: self.key_prefix,
+            health_check_timeout: self.health_check_timeout,
+            allow_env_override: env_override_allowed(),
+            max_attempts: self.max_attempts,
+            base_delay: self.base_delay,
+        }
+    }
+
+    /// Loads the AWS SDK configuration, unless a shared one was provided.
+    ///
+    /// # Returns
+    ///
+    /// The AWS SDK configuration honoring the configured region and credentials
+    async fn sdk_config(&self) -> SdkConfig {
+        let region = self.region.clone().map(Region::new);
+
+        match &self.shared_config {
+            Some(config) => {
+                let mut builder = config.to_builder();
+
+                if let Some(region) = region {
+                    builder = builder.region(region);
+                }
+
+                if let Some(provider) = &self.credentials_provider {
+                    builder = builder.credentials_provider(provider.clone());
+                }
+
+                builder.build()
+            }
+            None => {
+                let version = self
+                    .behavior_version
+                    .unwrap_or_else(BehaviorVersion::latest);
+                let mut loader = aws_config::defaults(version);
+
+                if let Some(region) = region {
+                    loader = loader.region(region);
+                }
+
+                if let Some(provider) = &self.credentials_provider {
+                    loader = loader.credentials_provider(provider.clone());
+                }
+
+                loader.load().await
+            }
+        }
+    }
+
+    /// Creates the Secrets Manager client from the loaded AWS configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The loaded AWS SDK configuration
+    ///
+    /// # Returns
+    ///
+    /// A Secrets Manager client honoring the configured endpoint URL, timeout and
+    /// trace propagation
+    fn sdk_client(&self, config: &SdkConfig) -> Client {
+        let mut builder = secretsmanager::config::Builder::from(config);
+
+        #[cfg(feature = "opentelemetry")]
+        if self.trace_propagation {
+            builder = builder.interceptor(TraceContextInterceptor);
+        }
+
+        if let Some(url) = &self.endpoint_url {
+            builder = builder.endpoint_url(url);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder =
+                builder.timeout_config(TimeoutConfig::builder().operation_timeout(timeout).build());
+        }
+
+        Client::from_conf(builder.build())
+    }
+
+    /// Returns the configured Secrets Manager client, creating it unless one was provided.
+    ///
+    /// # Returns
+    ///
+    /// The provided Secrets Manager client, or one created from the AWS configuration
+    async fn secrets_manager_client(&self) -> Client {
+        match &self.client {
+            Some(client) => client.clone(),
+            None => {
+                let config = self.sdk_config().await;
+                self.sdk_client(&config)
+            }
+        }
+    }
+
     /// Builds and returns an `AWSSecretClient` instance.
     ///
     /// This asynchronous method:
-    /// 1. Configures the AWS SDK
-    /// 2. Creates a Secrets Manager client
-    /// 3. Retrieves the secret
+    /// 1. Configures the AWS SDK, unless an SDK client or shared configuration was provided
+    /// 2. Creates a Secrets Manager client, unless an SDK client was provided
+    /// 3. Retrieves the secret, unless the warming strategy is `WarmingStrategy::Lazy`
     /// 4. Parses the secret JSON
     /// 5. Creates an `AWSSecretClient` with the parsed secrets
     ///
@@ -65,34 +892,148 @@ impl AWSSecretClientBuilder {
     ///
     /// * `Ok(AWSSecretClient)` - If the secret was successfully retrieved and parsed
     /// * `Err(SecretsManagerError)` - If any step failed
+    #[must_use = "this future must be awaited"]
     pub async fn build(&self) -> Result<AWSSecretClient, SecretsManagerError> {
-        let config = aws_config::load_defaults(BehaviorVersion::latest()).await;
-        let client = Client::new(&config);
-
-        let id = self.secret_id();
-
-        let output = match client.get_secret_value().secret_id(&id).send().await {
-            Err(err) => {
-                error!(
-                    error = err.to_string(),
-                    "failure send request to secret manager"
-                );
-                Err(SecretsManagerError::RequestFailure {})
-            }
-            Ok(s) => Ok(s),
-        }?;
+        self.build_request(self.request(), None).await
+    }
 
-        let Some(string) = output.secret_string() else {
-            error!("secret was not found");
-            return Err(SecretsManagerError::AwsSecretWasNotFound {});
-        };
+    /// Builds the `AWSSecretClient` of a request with the settings of the builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The secret, and optionally its version, to retrieve
+    /// * `client` - The Secrets Manager client to use, the configured one if `None`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AWSSecretClient)` - If the secret was successfully retrieved and parsed
+    /// * `Err(SecretsManagerError)` - If any step failed
+    async fn build_request(
+        &self,
+        request: SecretRequest,
+        client: Option<Client>,
+    ) -> Result<AWSSecretClient, SecretsManagerError> {
+        let span = info_span!("build", secret_id = %request.secret_id);
 
-        match serde_json::from_str(string) {
-            Err(err) => {
-                error!(error = err.to_string(), "error mapping secrets");
-                Err(SecretsManagerError::InternalError {})
-            }
-            Ok(v) => Ok(AWSSecretClient { secrets: v }),
+        async {
+            #[cfg(feature = "metrics")]
+            let started_at = std::time::Instant::now();
+
+            let client = match client {
+                Some(client) => client,
+                None => self.secrets_manager_client().await,
+            };
+
+            let secret_id = request.secret_id.clone();
+            let event_sink = self.event_sink.clone().map(EventSink::new).map(Arc::new);
+
+            let secret_client = match self.warming_strategy {
+                WarmingStrategy::EagerFail => {
+                    let result = fetch_with_retry(&client, &request).await;
+                    if let Some(sink) = &event_sink {
+                        sink.emit(SecretEvent::from_result(&request.secret_id, &result));
+                    }
+
+                    let fetched = result?;
+                    debug!(request_id = fetched.request_id, "secret retrieved from aws");
+
+                    AWSSecretClient::from_fetched(fetched, client.clone(), request)
+                }
+                WarmingStrategy::Lazy => {
+                    debug!("secret retrieval deferred to first access");
+                    AWSSecretClient::lazy(client.clone(), request)
+                }
+            };
+
+            let secret_client = AWSSecretClient {
+                event_sink,
+                ..secret_client
+            };
+
+            let secret_client = match self.metadata {
+                false => secret_client,
+                true => {
+                    let metadata = describe_secret(&client, &secret_id).await?;
+                    debug!("secret metadata retrieved from aws");
+
+                    AWSSecretClient {
+                        metadata: Some(metadata),
+                        ..secret_client
+                    }
+                }
+            };
+
+            #[cfg(feature = "metrics")]
+            let secret_client = match self.metrics {
+                false => secret_client,
+                true => {
+                    let prefix = self.metrics_prefix.as_deref().unwrap_or(DEFAULT_PREFIX);
+                    let metrics = AccessMetrics::register(prefix);
+                    record_build_duration(prefix, started_at.elapsed().as_secs_f64() * 1000.0);
+
+                    AWSSecretClient {
+                        metrics: Some(Arc::new(metrics)),
+                        ..secret_client
+                    }
+                }
+            };
+
+            Ok(secret_client)
         }
+        .instrument(span)
+        .await
+    }
+
+    /// Retrieves several AWS secrets concurrently.
+    ///
+    /// Every secret is built as by `build`, with the settings of this builder
+    /// except the secret id, e.g. its region, credentials, retries and key
+    /// transform. A single Secrets Manager client is shared, and the secrets are
+    /// retrieved concurrently on the calling task.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_keys` - The names/ids of the secrets to retrieve from AWS Secrets Manager
+    ///
+    /// # Returns
+    ///
+    /// A map from each secret id to the result of building its `AWSSecretClient`.
+    /// A failure for one secret does not prevent the others from being retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use secrets_manager::AWSSecretClientBuilder;
+    ///
+    /// # async fn example() {
+    /// let secrets = AWSSecretClientBuilder::new(String::new())
+    ///     .with_region("eu-west-1")
+    ///     .fetch_many(&["infra/db".to_string(), "infra/api".to_string()])
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn fetch_many(
+        &self,
+        secret_keys: &[String],
+    ) -> HashMap<String, Result<AWSSecretClient, SecretsManagerError>> {
+        let client = self.secrets_manager_client().await;
+
+        let builds = secret_keys
+            .iter()
+            .map(|id| {
+                let request = SecretRequest {
+                    secret_id: id.clone(),
+                    ..self.request()
+                };
+
+                Box::pin(self.build_request(request, Some(client.clone())))
+            })
+            .collect();
+
+        secret_keys
+            .iter()
+            .cloned()
+            .zip(join_all(builds).await)
+            .collect()
     }
 }
@@ -0,0 +1,110 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! axum extractor injecting secrets into handlers.
+//!
+//! This module provides the `InjectableSecret` extractor which reads a secret,
+//! named by a `SecretKey` marker type, from the `Arc<dyn SecretClient>` held in
+//! the state of an axum application.
+
+use crate::SecretClient;
+use axum_core::{
+    extract::{FromRef, FromRequestParts},
+    response::{IntoResponse, Response},
+};
+use http::{StatusCode, request::Parts};
+use std::{fmt, marker::PhantomData, sync::Arc};
+use tracing::error;
+
+/// Trait for the marker types naming the secret read by `InjectableSecret`.
+///
+/// With the `derive` feature, `#[derive(SecretKey)]` generates this
+/// implementation from a `#[secret_key("key-name")]` attribute.
+pub trait SecretKey {
+    /// The key of the secret
+    const KEY: &'static str;
+}
+
+/// Extractor reading the secret named by `K` from the application state.
+///
+/// The state must provide an `Arc<dyn SecretClient>` through `FromRef`. The
+/// secret is read with `get_by_key`, so clients holding their secrets in memory,
+/// such as `AWSSecretClient`, serve every request without calling their backend.
+/// If the secret couldn't be retrieved, the request is rejected with a
+/// `500 Internal Server Error` whose body never includes the underlying error.
+///
+/// # Examples
+///
+/// ```rust
+/// use axum::{Router, routing::get};
+/// use secrets_manager::{FakeSecretClient, InjectableSecret, SecretClient, SecretKey};
+/// use std::sync::Arc;
+///
+/// // Equivalent to `#[derive(SecretKey)] #[secret_key("api-key")] struct ApiKey;`
+/// struct ApiKey;
+///
+/// impl SecretKey for ApiKey {
+///     const KEY: &'static str = "api-key";
+/// }
+///
+/// async fn handler(InjectableSecret(api_key, ..): InjectableSecret<ApiKey>) -> String {
+///     format!("api key has {} characters", api_key.len())
+/// }
+///
+/// let client: Arc<dyn SecretClient> = Arc::new(FakeSecretClient::new());
+/// let app: Router = Router::new()
+///     .route("/", get(handler))
+///     .with_state(client);
+/// ```
+pub struct InjectableSecret<K: SecretKey>(pub String, pub PhantomData<fn() -> K>);
+
+impl<K: SecretKey> fmt::Debug for InjectableSecret<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InjectableSecret")
+            .field("key", &K::KEY)
+            .finish()
+    }
+}
+
+impl<K, S> FromRequestParts<S> for InjectableSecret<K>
+where
+    K: SecretKey,
+    S: Send + Sync,
+    Arc<dyn SecretClient>: FromRef<S>,
+{
+    type Rejection = SecretRejection;
+
+    async fn from_request_parts(
+        _parts: &mut Parts,
+        state: &S,
+    ) -> Result<InjectableSecret<K>, SecretRejection> {
+        let client = Arc::<dyn SecretClient>::from_ref(state);
+
+        match client.get_by_key(K::KEY) {
+            Err(err) => {
+                error!(
+                    key = K::KEY,
+                    error = err.to_string(),
+                    "failure to inject secret {}",
+                    K::KEY
+                );
+                Err(SecretRejection)
+            }
+            Ok(secret) => Ok(InjectableSecret(secret.into_string(), PhantomData)),
+        }
+    }
+}
+
+/// Rejection of `InjectableSecret` when the secret couldn't be retrieved.
+///
+/// It responds with a `500 Internal Server Error` and a generic message; the
+/// underlying error is only logged.
+#[derive(Debug)]
+pub struct SecretRejection;
+
+impl IntoResponse for SecretRejection {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, "secret unavailable").into_response()
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Azure Key Vault client implementation.
+//!
+//! This module provides the `AzureKeyVaultClient` which implements the `SecretClient` trait
+//! for retrieving secrets from Azure Key Vault.
+
+use crate::{SecretClient, SecretValue, blocking::block_on, errors::SecretsManagerError};
+use async_trait::async_trait;
+use azure_core::{
+    StatusCode,
+    error::{Error, ErrorKind},
+};
+use azure_security_keyvault::SecretClient as KeyVaultSecretClient;
+use dashmap::DashMap;
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use tracing::error;
+
+/// Client for accessing secrets from Azure Key Vault.
+///
+/// Unlike the AWS client, each secret is a separate Key Vault object: secrets
+/// are fetched individually the first time their name is requested and cached
+/// in memory afterwards.
+///
+/// Fetching a secret that is not cached yet from the synchronous `get_by_key`
+/// blocks the current thread, which requires a multi-threaded `tokio` runtime;
+/// prefer `get_by_key_async` from asynchronous code.
+pub struct AzureKeyVaultClient {
+    /// The Key Vault client used to fetch secrets
+    pub(crate) client: KeyVaultSecretClient,
+    /// The cached secrets, by name
+    pub(crate) secrets: DashMap<String, String>,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+#[async_trait]
+impl SecretClient for AzureKeyVaultClient {
+    /// Retrieves a secret by its name, fetching it from Key Vault if it is not cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret does not exist
+    /// * `Err(SecretsManagerError::NotSupported)` - If the secret must be fetched outside
+    ///   of a multi-threaded `tokio` runtime
+    /// * `Err(SecretsManagerError)` - If the request failed
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        if let Some(secret) = self.secrets.get(key) {
+            return Ok(SecretValue::from(secret.clone()));
+        }
+
+        block_on(key, self.fetch(key))?
+    }
+
+    /// Retrieves a secret by its name, fetching it from Key Vault if it is not cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret does not exist
+    /// * `Err(SecretsManagerError)` - If the request failed
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        if let Some(secret) = self.secrets.get(key) {
+            return Ok(SecretValue::from(secret.clone()));
+        }
+
+        self.fetch(key).await
+    }
+}
+
+impl AzureKeyVaultClient {
+    /// Fetches a secret from Key Vault and caches it.
+    async fn fetch(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        match self.client.get(key).await {
+            Err(err) => {
+                error!(
+                    error = err.to_string(),
+                    key = key,
+                    "failure to get secret from key vault"
+                );
+                Err(map_error(key, &err))
+            }
+            Ok(response) => {
+                self.secrets.insert(key.to_string(), response.value.clone());
+                Ok(SecretValue::from(response.value))
+            }
+        }
+    }
+}
+
+/// Maps a Key Vault error to the matching `SecretsManagerError`.
+fn map_error(key: &str, err: &Error) -> SecretsManagerError {
+    match err.kind() {
+        ErrorKind::HttpResponse {
+            status: StatusCode::NotFound,
+            ..
+        } => SecretsManagerError::SecretNotFound {
+            key: key.to_string(),
+        },
+        ErrorKind::HttpResponse {
+            status: StatusCode::TooManyRequests,
+            ..
+        } => SecretsManagerError::ThrottlingError,
+        ErrorKind::Credential => SecretsManagerError::internal(err),
+        _ => SecretsManagerError::request_failure(err),
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Azure Key Vault client builder module.
+//!
+//! This module provides the `AzureKeyVaultClientBuilder` for constructing instances of
+//! `AzureKeyVaultClient` with appropriate configuration.
+
+use crate::{AzureKeyVaultClient, errors::SecretsManagerError};
+use azure_core::authority_hosts::AZURE_PUBLIC_CLOUD;
+use azure_identity::ClientSecretCredential;
+use azure_security_keyvault::SecretClient as KeyVaultSecretClient;
+use dashmap::DashMap;
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use std::sync::Arc;
+use tracing::error;
+
+/// Builder for Azure Key Vault client instances.
+///
+/// This builder facilitates the creation of `AzureKeyVaultClient` instances
+/// authenticated with the client secret of an Azure AD app registration.
+#[derive(Default)]
+pub struct AzureKeyVaultClientBuilder {
+    /// The URL of the Key Vault
+    vault_url: String,
+    /// The Azure AD tenant of the app registration
+    tenant_id: String,
+    /// The client id of the app registration
+    client_id: String,
+    /// The client secret of the app registration
+    client_secret: String,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl AzureKeyVaultClientBuilder {
+    /// Creates a new builder for the given Key Vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_url` - The URL of the Key Vault, e.g. `https://my-vault.vault.azure.net`
+    /// * `tenant_id` - The Azure AD tenant of the app registration
+    /// * `client_id` - The client id of the app registration
+    /// * `client_secret` - The client secret of the app registration
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `AzureKeyVaultClientBuilder`
+    pub fn new(
+        vault_url: &str,
+        tenant_id: &str,
+        client_id: &str,
+        client_secret: &str,
+    ) -> AzureKeyVaultClientBuilder {
+        AzureKeyVaultClientBuilder {
+            vault_url: vault_url.to_string(),
+            tenant_id: tenant_id.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+        }
+    }
+
+    /// Builds and returns an `AzureKeyVaultClient` instance.
+    ///
+    /// No secret is fetched here; secrets are fetched lazily on first access.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AzureKeyVaultClient)` - If the Key Vault client was successfully created
+    /// * `Err(SecretsManagerError)` - If the vault URL is invalid
+    pub fn build(&self) -> Result<AzureKeyVaultClient, SecretsManagerError> {
+        let credential = Arc::new(ClientSecretCredential::new(
+            azure_core::new_http_client(),
+            AZURE_PUBLIC_CLOUD.clone(),
+            self.tenant_id.clone(),
+            self.client_id.clone(),
+            self.client_secret.clone(),
+        ));
+
+        match KeyVaultSecretClient::new(&self.vault_url, credential) {
+            Err(err) => {
+                error!(
+                    error = err.to_string(),
+                    "failure to create key vault client"
+                );
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(client) => Ok(AzureKeyVaultClient {
+                client,
+                secrets: DashMap::new(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Defines the `BinarySecretClient` trait for retrieving secrets that hold raw bytes.
+//!
+//! AWS Secrets Manager can store binary blobs that are not valid UTF-8. This trait
+//! complements `SecretClient` for backends that are able to serve such secrets.
+
+use crate::errors::SecretsManagerError;
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+
+/// Trait that defines the functionality for retrieving binary secrets.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{BinarySecretClient, FakeBinarySecretClient};
+/// use std::collections::HashMap;
+///
+/// let client = FakeBinarySecretClient::with_secrets(HashMap::from([(
+///     "certificate".to_string(),
+///     vec![0xde, 0xad, 0xbe, 0xef],
+/// )]));
+///
+/// assert_eq!(client.get_binary_by_key("certificate").unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+pub trait BinarySecretClient: Send + Sync {
+    /// Retrieves a binary secret value by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The secret value as raw bytes
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    fn get_binary_by_key(&self, key: &str) -> Result<Vec<u8>, SecretsManagerError>;
+}
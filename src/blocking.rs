@@ -0,0 +1,41 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Blocking bridge for lazily fetching backends.
+//!
+//! This module lets the synchronous `SecretClient::get_by_key` of backends that
+//! only offer an asynchronous API wait for a request to complete.
+
+use crate::errors::SecretsManagerError;
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tracing::error;
+
+/// Runs a future to completion from synchronous code running inside a `tokio` runtime.
+///
+/// Blocking a worker thread is only possible on a multi-threaded runtime; on any
+/// other runtime, or outside of one, the future is not run.
+///
+/// # Arguments
+///
+/// * `key` - The key the future fetches, used for logging
+/// * `future` - The future to run
+///
+/// # Returns
+///
+/// * `Ok(T)` - The output of the future
+/// * `Err(SecretsManagerError::NotSupported)` - If not called from a multi-threaded runtime
+pub(crate) fn block_on<F: Future>(key: &str, future: F) -> Result<F::Output, SecretsManagerError> {
+    let handle = match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => handle,
+        _ => {
+            error!(
+                key = key,
+                "fetching secret {} requires a multi-threaded tokio runtime", key
+            );
+            return Err(SecretsManagerError::NotSupported);
+        }
+    };
+
+    Ok(tokio::task::block_in_place(|| handle.block_on(future)))
+}
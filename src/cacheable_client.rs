@@ -0,0 +1,60 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Defines the `CacheableSecretClient` trait for clients caching their secrets.
+//!
+//! This trait gives explicit control over the cache of a client, e.g. to evict a
+//! secret known to have been rotated without waiting for its cached value to expire.
+
+use crate::{SecretValue, errors::SecretsManagerError};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+
+/// Trait for secret clients serving secrets from a cache that can be invalidated.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{CacheableSecretClient, CachedSecretClientBuilder};
+/// use std::{collections::HashMap, time::Duration};
+///
+/// let inner = HashMap::from([("api-key".to_string(), "value".to_string())]);
+/// let client = CachedSecretClientBuilder::new(inner, Duration::from_secs(60)).build();
+///
+/// assert_eq!(&*client.get_by_key_cached("api-key").unwrap(), "value");
+/// assert_eq!(&*client.get_by_key_cached("api-key").unwrap(), "value");
+///
+/// client.invalidate_cache("api-key");
+/// assert_eq!(&*client.get_by_key_cached("api-key").unwrap(), "value");
+///
+/// let stats = client.cache_stats();
+/// assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 2, 1));
+/// ```
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+pub trait CacheableSecretClient: Send + Sync {
+    /// Retrieves a secret by its key, serving it from the cache when possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The cached or freshly retrieved secret value
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    fn get_by_key_cached(&self, key: &str) -> Result<SecretValue, SecretsManagerError>;
+
+    /// Evicts the cached value of a secret, so the next access retrieves it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to evict
+    fn invalidate_cache(&self, key: &str);
+
+    /// Evicts every cached secret.
+    fn invalidate_all_cache(&self);
+}
@@ -0,0 +1,1079 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! TTL-based caching wrapper for secret clients.
+//!
+//! This module provides the `CachedSecretClient` which wraps any `SecretClient`
+//! implementation and re-fetches a secret from the inner client once its cached
+//! value is older than a configurable time-to-live. An optional `RetryBudget`
+//! stops calling the inner client for keys that keep failing.
+
+use crate::{
+    CacheableSecretClient, HealthCheckableSecretClient, SecretClient, SecretEvent, SecretEventKind,
+    SecretValue,
+    client::{join_all, json_array, json_object},
+    errors::SecretsManagerError,
+    events::EventSink,
+};
+#[cfg(feature = "stream")]
+use crate::{SecretChangedEvent, WatchableSecretClient};
+use async_trait::async_trait;
+use dashmap::DashMap;
+#[cfg(feature = "stream")]
+use futures::{Stream, StreamExt, stream};
+use serde_json::{Map, Value};
+#[cfg(feature = "stream")]
+use std::pin::Pin;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Arc, Mutex, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::{self, mpsc::UnboundedSender};
+use tracing::{error, warn};
+
+/// A cached secret value along with the moment it was fetched.
+struct CachedSecret {
+    /// The secret value
+    value: SecretValue,
+    /// When the value was fetched from the inner client
+    fetched_at: Instant,
+}
+
+/// The locks held while a key is retrieved from the inner client, so that
+/// concurrent misses for the key wait for a single retrieval.
+#[derive(Default)]
+struct Flight {
+    /// Held while a blocking lookup retrieves the key
+    blocking: Mutex<()>,
+    /// Held while an async lookup retrieves the key
+    pending: sync::Mutex<()>,
+}
+
+/// The consecutive failures of the inner client for a key.
+struct KeyFailures {
+    /// The number of consecutive failures
+    count: u32,
+    /// When the inner client last failed
+    last_failure: Instant,
+}
+
+/// Limit of the consecutive failures of a key after which the inner client of a
+/// `CachedSecretClient` is no longer called for it.
+///
+/// Once a key has failed `max_failures` consecutive times, lookups fail with
+/// `SecretsManagerError::BudgetExhausted` without reaching the inner client.
+/// After `cooldown` has elapsed since the last failure, a single lookup is
+/// delegated again: the budget of the key is restored if it succeeds, and
+/// exhausted again if it fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryBudget {
+    /// The number of consecutive failures exhausting the budget of a key
+    max_failures: u32,
+    /// How long after its last failure a key with an exhausted budget is tried again
+    cooldown: Duration,
+}
+
+impl RetryBudget {
+    /// Creates a new `RetryBudget`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_failures` - The number of consecutive failures exhausting the budget of a key
+    /// * `cooldown` - How long after its last failure a key is tried again
+    ///
+    /// # Returns
+    ///
+    /// A new `RetryBudget`
+    pub fn new(max_failures: u32, cooldown: Duration) -> RetryBudget {
+        RetryBudget {
+            max_failures,
+            cooldown,
+        }
+    }
+}
+
+/// Counters of the lookups in the cache of a `CachedSecretClient`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups served from the cache
+    pub hits: u64,
+    /// The number of lookups retrieving the secret from the inner client
+    pub misses: u64,
+    /// The number of cached values evicted, because they expired, were
+    /// invalidated or changed
+    pub evictions: u64,
+}
+
+/// Client that caches secrets from an inner `SecretClient` for a limited time.
+///
+/// Cached values are served until they are older than the configured TTL, at
+/// which point the next lookup fetches a fresh value from the inner client.
+/// The cache is a `DashMap` locked per shard rather than as a whole, so the
+/// client is `Send + Sync`, can be shared through an `Arc`, and lookups of
+/// different keys do not contend with each other. Cache misses are atomic per
+/// key: concurrent misses for a key wait for a single retrieval from the inner
+/// client, and are then served the value it cached.
+pub struct CachedSecretClient<C: SecretClient> {
+    /// The client secrets are fetched from on a cache miss, shared with the watch streams
+    inner: Arc<C>,
+    /// How long a cached value is considered fresh
+    ttl: Duration,
+    /// How old a cached value served when the inner client fails may be, if enabled
+    max_stale_age: Option<Duration>,
+    /// The cached secrets keyed by secret key, shared with the watch streams
+    cache: Arc<DashMap<String, CachedSecret>>,
+    /// The retrievals from the inner client in progress keyed by secret key
+    flights: DashMap<String, Arc<Flight>>,
+    /// The number of lookups served from the cache
+    hits: AtomicU64,
+    /// The number of lookups retrieving the secret from the inner client
+    misses: AtomicU64,
+    /// The number of cached values evicted
+    evictions: Arc<AtomicU64>,
+    /// The sink the cache events are sent to, if any
+    event_sink: Option<Arc<EventSink>>,
+    /// The limit of the consecutive failures of a key, if enabled
+    retry_budget: Option<RetryBudget>,
+    /// The consecutive failures of the inner client keyed by secret key
+    failures: Mutex<HashMap<String, KeyFailures>>,
+}
+
+/// Shows the TTL of the cache and how long until the first cached value expires,
+/// `expired` if no value is fresh, e.g. in startup logs.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{CachedSecretClientBuilder, SecretClient};
+/// use std::{collections::HashMap, time::Duration};
+///
+/// let inner = HashMap::from([("api-key", "value")]);
+/// let client = CachedSecretClientBuilder::new(inner, Duration::from_secs(60)).build();
+/// assert_eq!(
+///     client.to_string(),
+///     r#"CachedSecretClient { ttl: "60s", expires_in: "expired" }"#
+/// );
+///
+/// let _ = client.get_by_key("api-key");
+/// assert!(!client.to_string().contains("expired"));
+/// ```
+impl<C: SecretClient> fmt::Display for CachedSecretClient<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expires_in = self
+            .cache
+            .iter()
+            .filter_map(|secret| self.ttl.checked_sub(secret.fetched_at.elapsed()))
+            .min()
+            .map_or_else(|| "expired".to_string(), format_duration);
+
+        write!(
+            f,
+            "CachedSecretClient {{ ttl: {:?}, expires_in: {:?} }}",
+            format_duration(self.ttl),
+            expires_in
+        )
+    }
+}
+
+#[async_trait]
+impl<C: SecretClient> SecretClient for CachedSecretClient<C> {
+    /// Retrieves a secret by its key, serving it from the cache while it is fresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.get_by_key_cached(key)
+    }
+
+    /// Lists the keys available from the inner client.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys reported by the inner client
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.inner.list_keys()
+    }
+
+    /// Retrieves a secret by its key asynchronously, serving it from the cache while it is fresh.
+    ///
+    /// On a cache miss the secret is retrieved with the `get_by_key_async` of the
+    /// inner client, and cached as by `get_by_key_cached`. Concurrent misses for
+    /// the key wait for a single retrieval without blocking their thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::BudgetExhausted)` - If the retry budget of the key is exhausted
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        if let Some(secret) = self.fresh(key) {
+            self.hit(key);
+            return Ok(secret);
+        }
+
+        let flight = self.flight(key);
+        let result = {
+            let _pending = flight.pending.lock().await;
+
+            match self.fresh(key) {
+                // Another lookup retrieved the key while this one waited
+                Some(secret) => {
+                    self.hit(key);
+                    Ok(secret)
+                }
+                None => {
+                    self.misses.fetch_add(1, Ordering::Relaxed);
+                    let result = match self.budget_exhausted(key) {
+                        true => Err(SecretsManagerError::BudgetExhausted {
+                            key: key.to_string(),
+                        }),
+                        false => {
+                            let result = self.inner.get_by_key_async(key).await;
+                            self.record_attempt(key, result.is_ok());
+                            result
+                        }
+                    };
+
+                    self.refreshed(key, result)
+                }
+            }
+        };
+        self.landed(key, flight);
+
+        result
+    }
+
+    /// Retrieves a secret by its key as a JSON object.
+    ///
+    /// A fresh cached value is parsed as JSON. Otherwise the object is retrieved
+    /// with the `get_json_object_by_key` of the inner client, so that nested
+    /// objects, e.g. of an `AWSSecretClient`, are supported, and cached as by
+    /// `get_json_cached`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError::BudgetExhausted)` - If the retry budget of the key is exhausted
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{CacheStats, CachedSecretClientBuilder, SecretClient};
+    /// use std::{collections::HashMap, time::Duration};
+    ///
+    /// let inner = HashMap::from([("endpoints", r#"{"primary": "db-1"}"#)]);
+    /// let client = CachedSecretClientBuilder::new(inner, Duration::from_secs(60)).build();
+    ///
+    /// assert_eq!(client.get_json_object_by_key("endpoints").unwrap()["primary"], "db-1");
+    /// assert_eq!(client.get_json_object_by_key("endpoints").unwrap()["primary"], "db-1");
+    /// assert_eq!(
+    ///     client.cache_stats(),
+    ///     CacheStats { hits: 1, misses: 1, evictions: 0 }
+    /// );
+    /// ```
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.get_json_cached(
+            key,
+            |inner| inner.get_json_object_by_key(key),
+            Value::Object,
+            json_object,
+        )
+    }
+
+    /// Retrieves a secret by its key as a JSON array.
+    ///
+    /// A fresh cached value is parsed as JSON. Otherwise the array is retrieved
+    /// with the `get_json_array_by_key` of the inner client, so that nested
+    /// arrays, e.g. of an `AWSSecretClient`, are supported, and cached as by
+    /// `get_json_cached`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError::BudgetExhausted)` - If the retry budget of the key is exhausted
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.get_json_cached(
+            key,
+            |inner| inner.get_json_array_by_key(key),
+            Value::Array,
+            json_array,
+        )
+    }
+
+    /// Retrieves several secrets from the inner client in parallel and caches them.
+    ///
+    /// The keys are retrieved concurrently with the `get_by_key_async` of the inner
+    /// client, on the task awaiting the prefetch, so the inner client may borrow
+    /// data. Every key is retrieved, even after a failure, and the retrieved
+    /// secrets are cached whether or not the other keys failed.
+    /// Retrievals count against the retry budget of their key, and the keys whose
+    /// budget is exhausted are not retrieved.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys identifying the secrets to load
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If every secret was retrieved
+    /// * `Err(SecretsManagerError::AggregateError)` - The errors of the keys that couldn't be retrieved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{CacheableSecretClient, CachedSecretClientBuilder, SecretClient};
+    /// use std::{collections::HashMap, time::Duration};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let secrets = HashMap::from([("api-key", "value"), ("db-pass", "secret")]);
+    ///
+    /// // The inner client may borrow data, e.g. secrets owned by the caller
+    /// let client = CachedSecretClientBuilder::new(&secrets, Duration::from_secs(60)).build();
+    ///
+    /// client.prefetch(&["api-key", "db-pass"]).await?;
+    ///
+    /// let _ = client.get_by_key_cached("api-key");
+    /// assert_eq!(client.cache_stats().hits, 1);
+    /// assert_eq!(client.cache_stats().misses, 2);
+    ///
+    /// let err = client.prefetch(&["api-key", "db-user"]).await.unwrap_err();
+    /// assert!(err.errors()[0].is_not_found());
+    /// # Ok(())
+    /// # }
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(example()).unwrap();
+    /// ```
+    async fn prefetch<'a>(&self, keys: &[&'a str]) -> Result<(), SecretsManagerError> {
+        let mut failures = Vec::new();
+        let mut fetched = Vec::new();
+        let mut fetches = Vec::new();
+
+        for key in keys {
+            if self.budget_exhausted(key) {
+                failures.push(SecretsManagerError::BudgetExhausted {
+                    key: key.to_string(),
+                });
+                continue;
+            }
+
+            fetched.push(*key);
+            fetches.push(self.inner.get_by_key_async(key));
+        }
+
+        for (key, result) in fetched.into_iter().zip(join_all(fetches).await) {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            self.record_attempt(key, result.is_ok());
+
+            match result {
+                Err(err) => {
+                    error!(
+                        key = key,
+                        error = err.to_string(),
+                        "failure to prefetch secret {}",
+                        key
+                    );
+                    failures.push(err);
+                }
+                Ok(value) => self.store(key, &value),
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(SecretsManagerError::AggregateError(failures));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: HealthCheckableSecretClient> HealthCheckableSecretClient for CachedSecretClient<C> {
+    /// Checks whether the backend of the inner client is reachable.
+    ///
+    /// The cache is not consulted, so a cached secret does not hide an
+    /// unreachable backend.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend of the inner client is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+impl<C: SecretClient> CacheableSecretClient for CachedSecretClient<C> {
+    /// Retrieves a secret by its key, serving it from the cache while it is fresh.
+    ///
+    /// On a cache miss only the key is locked while fetching from the inner
+    /// client, so a slow retrieval does not block the lookups of other keys.
+    /// Concurrent misses for the same key wait for that retrieval, and are served
+    /// the value it cached rather than fetching the key again.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::BudgetExhausted)` - If the retry budget of the key is exhausted
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{
+    ///     CacheableSecretClient, CachedSecretClientBuilder, SecretClient, SecretValue,
+    ///     errors::SecretsManagerError,
+    /// };
+    /// use std::{
+    ///     sync::atomic::{AtomicU32, Ordering},
+    ///     thread,
+    ///     time::Duration,
+    /// };
+    ///
+    /// // Counts its slow retrievals
+    /// struct SlowClient(AtomicU32);
+    ///
+    /// impl SecretClient for SlowClient {
+    ///     fn get_by_key(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+    ///         self.0.fetch_add(1, Ordering::Relaxed);
+    ///         thread::sleep(Duration::from_millis(50));
+    ///         Ok(SecretValue::from("value".to_string()))
+    ///     }
+    /// }
+    ///
+    /// let client = CachedSecretClientBuilder::new(SlowClient(AtomicU32::new(0)), Duration::from_secs(60))
+    ///     .build();
+    ///
+    /// thread::scope(|scope| {
+    ///     for _ in 0..8 {
+    ///         scope.spawn(|| assert_eq!(&*client.get_by_key_cached("api-key").unwrap(), "value"));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(client.cache_stats().misses, 1);
+    /// assert_eq!(client.cache_stats().hits, 7);
+    /// ```
+    fn get_by_key_cached(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        if let Some(secret) = self.fresh(key) {
+            self.hit(key);
+            return Ok(secret);
+        }
+
+        self.single_flight(key, || match self.fresh(key) {
+            // Another lookup retrieved the key while this one waited
+            Some(secret) => {
+                self.hit(key);
+                Ok(secret)
+            }
+            None => self.refresh(key, || self.inner.get_by_key(key)),
+        })
+    }
+
+    /// Evicts the cached value of a secret, so the next access retrieves it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to evict
+    fn invalidate_cache(&self, key: &str) {
+        if self.cache.remove(key).is_some() {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Evicts every cached secret.
+    fn invalidate_all_cache(&self) {
+        self.cache.retain(|_, _| {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+            false
+        });
+    }
+}
+
+impl<C: SecretClient> CachedSecretClient<C> {
+    /// Returns the counters of the lookups in the cache since the client was built.
+    ///
+    /// # Returns
+    ///
+    /// The number of cache hits, misses and evictions
+    pub fn cache_stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns whether the retry budget of `key` is exhausted and its cooldown still running.
+    fn budget_exhausted(&self, key: &str) -> bool {
+        let Some(budget) = self.retry_budget else {
+            return false;
+        };
+
+        let failures = self.failures.lock().unwrap_or_else(PoisonError::into_inner);
+        let exhausted = failures.get(key).is_some_and(|failures| {
+            failures.count >= budget.max_failures
+                && failures.last_failure.elapsed() < budget.cooldown
+        });
+
+        if exhausted {
+            warn!(key = key, "retry budget of secret {} is exhausted", key);
+        }
+
+        exhausted
+    }
+
+    /// Retrieves a JSON value by its key, serving it from the cache while it is fresh.
+    ///
+    /// On a cache miss the value is retrieved with `fetch`, counting against the
+    /// retry budget of the key, and cached as its JSON text, so that a later
+    /// `get_by_key` serves that text while it is fresh. A failure is replaced by
+    /// the stale cached value, if enabled, as by `get_by_key_cached`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    /// * `fetch` - Retrieves the value from the inner client
+    /// * `to_json` - Converts the retrieved value to JSON, to be cached
+    /// * `from_json` - Converts a cached value back, failing if it has another JSON type
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The secret value
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value has another JSON type
+    /// * `Err(SecretsManagerError::BudgetExhausted)` - If the retry budget of the key is exhausted
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_cached<T>(
+        &self,
+        key: &str,
+        fetch: impl FnOnce(&C) -> Result<T, SecretsManagerError>,
+        to_json: impl FnOnce(T) -> Value,
+        from_json: fn(&str, Value) -> Result<T, SecretsManagerError>,
+    ) -> Result<T, SecretsManagerError> {
+        if let Some(secret) = self.fresh(key) {
+            self.hit(key);
+            return from_json(key, Value::String(secret.into_string()));
+        }
+
+        let secret = self.single_flight(key, || match self.fresh(key) {
+            // Another lookup retrieved the key while this one waited
+            Some(secret) => {
+                self.hit(key);
+                Ok(secret)
+            }
+            None => self.refresh(key, || {
+                fetch(&self.inner).map(|value| SecretValue::from(to_json(value).to_string()))
+            }),
+        })?;
+
+        from_json(key, Value::String(secret.into_string()))
+    }
+
+    /// Runs a blocking `lookup` of `key`, once the lookups of the key in progress are done.
+    fn single_flight<T>(&self, key: &str, lookup: impl FnOnce() -> T) -> T {
+        let flight = self.flight(key);
+        let result = {
+            let _blocking = flight
+                .blocking
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            lookup()
+        };
+        self.landed(key, flight);
+
+        result
+    }
+
+    /// Returns the locks of the retrievals of `key`, registering them if none is in progress.
+    fn flight(&self, key: &str) -> Arc<Flight> {
+        Arc::clone(&self.flights.entry(key.to_string()).or_default())
+    }
+
+    /// Unregisters the locks of the retrievals of `key` once no lookup holds them.
+    fn landed(&self, key: &str, flight: Arc<Flight>) {
+        drop(flight);
+        self.flights
+            .remove_if(key, |_, flight| Arc::strong_count(flight) == 1);
+    }
+
+    /// Retrieves `key` with `fetch` after a cache miss and caches it, unless the
+    /// retry budget of the key is exhausted.
+    fn refresh(
+        &self,
+        key: &str,
+        fetch: impl FnOnce() -> Result<SecretValue, SecretsManagerError>,
+    ) -> Result<SecretValue, SecretsManagerError> {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let result = match self.budget_exhausted(key) {
+            true => Err(SecretsManagerError::BudgetExhausted {
+                key: key.to_string(),
+            }),
+            false => {
+                let result = fetch();
+                self.record_attempt(key, result.is_ok());
+                result
+            }
+        };
+
+        self.refreshed(key, result)
+    }
+
+    /// Records whether a retrieval of `key` from the inner client succeeded.
+    fn record_attempt(&self, key: &str, success: bool) {
+        if self.retry_budget.is_none() {
+            return;
+        }
+
+        let mut failures = self.failures.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match success {
+            true => {
+                failures.remove(key);
+            }
+            false => {
+                let failures = failures.entry(key.to_string()).or_insert(KeyFailures {
+                    count: 0,
+                    last_failure: Instant::now(),
+                });
+                failures.count += 1;
+                failures.last_failure = Instant::now();
+            }
+        }
+    }
+
+    /// Caches the result of a retrieval of `key` after a cache miss.
+    ///
+    /// A retrieved value replaces the cached one. A failure other than the secret
+    /// not being found is replaced by the stale cached value, if enabled.
+    fn refreshed(
+        &self,
+        key: &str,
+        result: Result<SecretValue, SecretsManagerError>,
+    ) -> Result<SecretValue, SecretsManagerError> {
+        let value = match result {
+            Err(err) if !err.is_not_found() => {
+                let Some(secret) = self.stale(key) else {
+                    return Err(err);
+                };
+
+                warn!(
+                    key = key,
+                    error = err.to_string(),
+                    "serving stale secret {} after failure to refresh it",
+                    key
+                );
+                return Ok(secret);
+            }
+            result => result?,
+        };
+
+        self.store(key, &value);
+
+        Ok(value)
+    }
+
+    /// Caches a value retrieved from the inner client, replacing the previous one.
+    ///
+    /// A replaced value counts as evicted, and emits a `Rotated` event if it changed.
+    fn store(&self, key: &str, value: &SecretValue) {
+        let expired = self.cache.insert(
+            key.to_string(),
+            CachedSecret {
+                value: value.clone(),
+                fetched_at: Instant::now(),
+            },
+        );
+
+        if let Some(expired) = expired {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+
+            if expired.value != *value {
+                self.emit(SecretEventKind::Rotated, key);
+            }
+        }
+    }
+
+    /// Records a lookup served from the cache.
+    fn hit(&self, key: &str) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        self.emit(SecretEventKind::CacheHit, key);
+    }
+
+    /// Sends an event to the event sink, if any.
+    fn emit(&self, kind: SecretEventKind, key: &str) {
+        if let Some(sink) = &self.event_sink {
+            sink.emit(SecretEvent::new(kind, key));
+        }
+    }
+
+    /// Returns the cached value for `key` if it is younger than the maximum stale age.
+    fn stale(&self, key: &str) -> Option<SecretValue> {
+        let max_stale_age = self.max_stale_age?;
+
+        self.cache
+            .get(key)
+            .filter(|secret| secret.fetched_at.elapsed() < max_stale_age)
+            .map(|secret| secret.value.clone())
+    }
+
+    /// Returns the cached value for `key` if it is younger than the TTL.
+    fn fresh(&self, key: &str) -> Option<SecretValue> {
+        self.cache
+            .get(key)
+            .filter(|secret| secret.fetched_at.elapsed() < self.ttl)
+            .map(|secret| secret.value.clone())
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<C: SecretClient + 'static> WatchableSecretClient for CachedSecretClient<C> {
+    /// Watches the secrets of the inner client for changes.
+    ///
+    /// Every TTL, all secrets are fetched from the inner client and compared
+    /// with the previous snapshot. The cached value of every changed key is
+    /// evicted, so the next lookup serves the new value. Snapshots that fail
+    /// are logged and skipped. A zero TTL polls every millisecond.
+    ///
+    /// The stream shares the cache of the client rather than borrowing it, so it
+    /// may outlive the client, e.g. on a spawned task. Its timer is created on
+    /// the first poll, so the stream may be created outside a Tokio runtime.
+    ///
+    /// # Returns
+    ///
+    /// A stream of the changes of the secrets
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use secrets_manager::{
+    ///     CachedSecretClientBuilder, SecretClient, SecretValue, WatchableSecretClient,
+    ///     errors::SecretsManagerError,
+    /// };
+    /// use std::{
+    ///     sync::atomic::{AtomicU32, Ordering},
+    ///     time::Duration,
+    /// };
+    ///
+    /// // Rotates its secret on every retrieval
+    /// struct RotatingClient(AtomicU32);
+    ///
+    /// impl SecretClient for RotatingClient {
+    ///     fn get_by_key(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+    ///         let version = self.0.fetch_add(1, Ordering::Relaxed);
+    ///         Ok(SecretValue::from(format!("v{version}")))
+    ///     }
+    ///
+    ///     fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+    ///         Ok(vec!["api-key".to_string()])
+    ///     }
+    /// }
+    ///
+    /// let client = CachedSecretClientBuilder::new(RotatingClient(AtomicU32::new(0)), Duration::ZERO)
+    ///     .build();
+    /// let mut changes = client.watch();
+    /// drop(client);
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let event = changes.next().await.unwrap();
+    /// assert_eq!(event.key, "api-key");
+    /// assert_eq!(event.old.as_deref(), Some("v0"));
+    /// assert_eq!(event.new, "v1");
+    /// # });
+    /// ```
+    fn watch(&self) -> Pin<Box<dyn Stream<Item = SecretChangedEvent> + Send>> {
+        let period = self.ttl.max(MIN_WATCH_PERIOD);
+        let watcher = Watcher {
+            inner: Arc::clone(&self.inner),
+            cache: Arc::clone(&self.cache),
+            evictions: Arc::clone(&self.evictions),
+            event_sink: self.event_sink.clone(),
+        };
+
+        let polls = stream::unfold(
+            (watcher, None, None),
+            move |(watcher, ticker, previous)| async move {
+                let mut ticker = ticker.unwrap_or_else(|| tokio::time::interval(period));
+                ticker.tick().await;
+
+                let latest = match watcher.inner.get_all() {
+                    Err(err) => {
+                        error!(error = err.to_string(), "failure to snapshot secrets");
+                        return Some((Vec::new(), (watcher, Some(ticker), previous)));
+                    }
+                    Ok(s) => s,
+                };
+
+                let events = match &previous {
+                    Some(previous) => watcher.changes(previous, &latest),
+                    None => Vec::new(),
+                };
+
+                Some((events, (watcher, Some(ticker), Some(latest))))
+            },
+        );
+
+        Box::pin(polls.flat_map(stream::iter))
+    }
+}
+
+/// The shortest period between two snapshots of a watch stream.
+#[cfg(feature = "stream")]
+const MIN_WATCH_PERIOD: Duration = Duration::from_millis(1);
+
+/// The state of a `CachedSecretClient` shared with its watch streams.
+#[cfg(feature = "stream")]
+struct Watcher<C: SecretClient> {
+    /// The client the snapshots are fetched from
+    inner: Arc<C>,
+    /// The cached secrets the changed keys are evicted from
+    cache: Arc<DashMap<String, CachedSecret>>,
+    /// The number of cached values evicted
+    evictions: Arc<AtomicU64>,
+    /// The sink the `Rotated` events are sent to, if any
+    event_sink: Option<Arc<EventSink>>,
+}
+
+#[cfg(feature = "stream")]
+impl<C: SecretClient> Watcher<C> {
+    /// Returns the changes between two snapshots and evicts the changed keys from the cache.
+    fn changes(
+        &self,
+        previous: &HashMap<String, SecretValue>,
+        latest: &HashMap<String, SecretValue>,
+    ) -> Vec<SecretChangedEvent> {
+        let events = latest
+            .iter()
+            .filter(|(key, new)| previous.get(*key) != Some(new))
+            .map(|(key, new)| SecretChangedEvent {
+                key: key.clone(),
+                old: previous.get(key).map(|old| old.as_ref().to_string()),
+                new: new.as_ref().to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        for event in &events {
+            if self.cache.remove(&event.key).is_some() {
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+
+            if let (Some(sink), Some(_)) = (&self.event_sink, &event.old) {
+                sink.emit(SecretEvent::new(SecretEventKind::Rotated, &event.key));
+            }
+        }
+
+        events
+    }
+}
+
+/// Formats a duration in whole seconds, or in milliseconds below a second.
+fn format_duration(duration: Duration) -> String {
+    match duration.as_secs() {
+        0 => format!("{}ms", duration.as_millis()),
+        secs => format!("{secs}s"),
+    }
+}
+
+/// Builder for `CachedSecretClient` instances.
+pub struct CachedSecretClientBuilder<C: SecretClient> {
+    /// The client to wrap
+    inner: C,
+    /// How long a cached value is considered fresh
+    ttl: Duration,
+    /// How old a cached value served when the inner client fails may be, if enabled
+    max_stale_age: Option<Duration>,
+    /// The sender the cache events are sent to, if any
+    event_sink: Option<UnboundedSender<SecretEvent>>,
+    /// The limit of the consecutive failures of a key, if enabled
+    retry_budget: Option<RetryBudget>,
+}
+
+impl<C: SecretClient> CachedSecretClientBuilder<C> {
+    /// Creates a new builder wrapping the given client.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client secrets are fetched from on a cache miss
+    /// * `ttl` - How long a fetched secret is served from the cache
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `CachedSecretClientBuilder`
+    pub fn new(inner: C, ttl: Duration) -> CachedSecretClientBuilder<C> {
+        CachedSecretClientBuilder {
+            inner,
+            ttl,
+            max_stale_age: None,
+            event_sink: None,
+            retry_budget: None,
+        }
+    }
+
+    /// Emits a `CacheHit` event for every secret served from the cache, and a
+    /// `Rotated` event for every refreshed secret whose value changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The sender the events are sent to
+    ///
+    /// # Returns
+    ///
+    /// The builder with the event sink configured
+    pub fn with_event_sink(
+        mut self,
+        sender: UnboundedSender<SecretEvent>,
+    ) -> CachedSecretClientBuilder<C> {
+        self.event_sink = Some(sender);
+        self
+    }
+
+    /// Serves expired cached values while the inner client fails to refresh them.
+    ///
+    /// When refreshing an expired secret fails, its cached value is returned
+    /// instead of the error, with a warning, as long as it was fetched less than
+    /// `max_stale_age` ago. Older values are not served and the error is returned,
+    /// so that a short backend outage does not take the whole service down.
+    /// Secrets reported as not found by the inner client are never served stale.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_stale_age` - How long after being fetched a cached value may still be served
+    ///
+    /// # Returns
+    ///
+    /// The builder with stale values served on error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{
+    ///     CachedSecretClientBuilder, SecretClient, SecretValue, errors::SecretsManagerError,
+    /// };
+    /// use std::{
+    ///     sync::{Arc, atomic::{AtomicBool, Ordering}},
+    ///     time::Duration,
+    /// };
+    ///
+    /// struct FlakyClient(AtomicBool);
+    ///
+    /// impl SecretClient for FlakyClient {
+    ///     fn get_by_key(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+    ///         match self.0.load(Ordering::Relaxed) {
+    ///             true => Err(SecretsManagerError::Timeout),
+    ///             false => Ok(SecretValue::from("value".to_string())),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let inner = Arc::new(FlakyClient(AtomicBool::new(false)));
+    /// let client = CachedSecretClientBuilder::new(inner.clone(), Duration::ZERO)
+    ///     .with_stale_on_error(Duration::from_secs(300))
+    ///     .build();
+    ///
+    /// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+    ///
+    /// inner.0.store(true, Ordering::Relaxed);
+    /// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+    /// ```
+    pub fn with_stale_on_error(mut self, max_stale_age: Duration) -> CachedSecretClientBuilder<C> {
+        self.max_stale_age = Some(max_stale_age);
+        self
+    }
+
+    /// Stops calling the inner client for keys that keep failing.
+    ///
+    /// Every failure of the inner client counts against the budget of the key,
+    /// including secrets reported as not found, so that a caller looking up a
+    /// missing secret in a loop does not send a request to the backend each time.
+    /// A lookup refused by an exhausted budget may still be served stale, see
+    /// [`CachedSecretClientBuilder::with_stale_on_error`].
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - The limit of the consecutive failures of a key
+    ///
+    /// # Returns
+    ///
+    /// The builder with the retry budget configured
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{
+    ///     CachedSecretClientBuilder, RetryBudget, SecretClient, errors::SecretsManagerError,
+    /// };
+    /// use std::{collections::HashMap, time::Duration};
+    ///
+    /// let inner: HashMap<&str, &str> = HashMap::new();
+    /// let client = CachedSecretClientBuilder::new(inner, Duration::from_secs(60))
+    ///     .with_retry_budget(RetryBudget::new(2, Duration::from_secs(30)))
+    ///     .build();
+    ///
+    /// assert!(client.get_by_key("missing").unwrap_err().is_not_found());
+    /// assert!(client.get_by_key("missing").unwrap_err().is_not_found());
+    /// assert_eq!(
+    ///     client.get_by_key("missing"),
+    ///     Err(SecretsManagerError::BudgetExhausted { key: "missing".to_string() })
+    /// );
+    /// assert_eq!(
+    ///     client.get_json_object_by_key("missing"),
+    ///     Err(SecretsManagerError::BudgetExhausted { key: "missing".to_string() })
+    /// );
+    /// ```
+    pub fn with_retry_budget(mut self, budget: RetryBudget) -> CachedSecretClientBuilder<C> {
+        self.retry_budget = Some(budget);
+        self
+    }
+
+    /// Builds and returns a `CachedSecretClient` instance with an empty cache.
+    ///
+    /// # Returns
+    ///
+    /// A new `CachedSecretClient`
+    pub fn build(self) -> CachedSecretClient<C> {
+        CachedSecretClient {
+            inner: Arc::new(self.inner),
+            ttl: self.ttl,
+            max_stale_age: self.max_stale_age,
+            cache: Arc::new(DashMap::new()),
+            flights: DashMap::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: Arc::new(AtomicU64::new(0)),
+            event_sink: self
+                .event_sink
+                .map(|sender| Arc::new(EventSink::new(sender))),
+            retry_budget: self.retry_budget,
+            failures: Mutex::new(HashMap::new()),
+        }
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Circuit breaker secret client implementation.
+//!
+//! This module provides the `CircuitBreakerSecretClient` which stops calling an
+//! inner `SecretClient` after repeated backend failures, so that callers fail
+//! immediately during an outage instead of waiting for every request to time out.
+
+use crate::{HealthCheckableSecretClient, SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::{
+    sync::atomic::{AtomicU8, AtomicU32, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Calls are delegated to the inner client.
+const CLOSED: u8 = 0;
+/// Calls fail immediately until the reset timeout elapses.
+const OPEN: u8 = 1;
+/// A single trial call is delegated to decide whether to close the circuit.
+const HALF_OPEN: u8 = 2;
+
+/// Client that short-circuits an inner client after consecutive backend failures.
+///
+/// The circuit starts closed. After `failure_threshold` consecutive retryable
+/// failures, see [`SecretsManagerError::is_retryable`], it opens and every call
+/// fails with `SecretsManagerError::CircuitOpen`. Once `reset_timeout` has
+/// elapsed, the circuit is half-open: the next call is delegated as a trial,
+/// closing the circuit if it succeeds and opening it again if it fails.
+/// Errors such as `SecretNotFound` mean the backend is up and never trip the circuit.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{
+///     CircuitBreakerSecretClient, SecretClient, SecretValue, errors::SecretsManagerError,
+/// };
+/// use std::{
+///     sync::atomic::{AtomicBool, Ordering},
+///     time::Duration,
+/// };
+///
+/// struct FlakyClient(AtomicBool);
+///
+/// impl SecretClient for FlakyClient {
+///     fn get_by_key(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+///         match self.0.load(Ordering::SeqCst) {
+///             true => Ok(SecretValue::from("value".to_string())),
+///             false => Err(SecretsManagerError::request_failure("unreachable")),
+///         }
+///     }
+/// }
+///
+/// let client = CircuitBreakerSecretClient::new(
+///     FlakyClient(AtomicBool::new(false)),
+///     2,
+///     Duration::from_millis(50),
+/// );
+///
+/// // Closed: failures are returned until the threshold is reached
+/// assert!(matches!(client.get_by_key("key"), Err(SecretsManagerError::RequestFailure { .. })));
+/// assert!(matches!(client.get_by_key("key"), Err(SecretsManagerError::RequestFailure { .. })));
+///
+/// // Open: calls fail immediately without reaching the inner client
+/// assert_eq!(client.get_by_key("key"), Err(SecretsManagerError::CircuitOpen));
+///
+/// // Half-open: after the reset timeout a failed trial opens the circuit again
+/// std::thread::sleep(Duration::from_millis(50));
+/// assert!(matches!(client.get_by_key("key"), Err(SecretsManagerError::RequestFailure { .. })));
+/// assert_eq!(client.get_by_key("key"), Err(SecretsManagerError::CircuitOpen));
+///
+/// // Half-open: a successful trial closes the circuit
+/// std::thread::sleep(Duration::from_millis(50));
+/// client.inner().0.store(true, Ordering::SeqCst);
+/// assert!(client.get_by_key("key").is_ok());
+/// assert!(client.get_by_key("key").is_ok());
+/// ```
+pub struct CircuitBreakerSecretClient<C: SecretClient> {
+    /// The client calls are delegated to while the circuit is closed
+    inner: C,
+    /// The number of consecutive failures opening the circuit
+    failure_threshold: u32,
+    /// The duration the circuit stays open before a trial call
+    reset_timeout: Duration,
+    /// The state of the circuit, one of `CLOSED`, `OPEN` or `HALF_OPEN`
+    state: AtomicU8,
+    /// The number of consecutive failures while closed
+    failures: AtomicU32,
+    /// The time the circuit was last opened, in nanoseconds since `created_at`
+    opened_at: AtomicU64,
+    /// The reference point of `opened_at`
+    created_at: Instant,
+}
+
+#[async_trait]
+impl<C: SecretClient> SecretClient for CircuitBreakerSecretClient<C> {
+    /// Retrieves a secret from the inner client unless the circuit is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::CircuitOpen)` - If the circuit is open
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.call(|| self.inner.get_by_key(key))
+    }
+
+    /// Lists the keys available from the inner client unless the circuit is open.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys reported by the inner client
+    /// * `Err(SecretsManagerError::CircuitOpen)` - If the circuit is open
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.call(|| self.inner.list_keys())
+    }
+
+    /// Retrieves a secret asynchronously from the inner client unless the circuit is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::CircuitOpen)` - If the circuit is open
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let trial = self.admit()?;
+        let result = self.inner.get_by_key_async(key).await;
+        self.record(&result, trial);
+        result
+    }
+
+    /// Retrieves a secret from the inner client as a JSON object unless the circuit is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError::CircuitOpen)` - If the circuit is open
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.call(|| self.inner.get_json_object_by_key(key))
+    }
+
+    /// Retrieves a secret from the inner client as a JSON array unless the circuit is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError::CircuitOpen)` - If the circuit is open
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.call(|| self.inner.get_json_array_by_key(key))
+    }
+}
+
+#[async_trait]
+impl<C: HealthCheckableSecretClient> HealthCheckableSecretClient for CircuitBreakerSecretClient<C> {
+    /// Checks whether the backend of the inner client is reachable.
+    ///
+    /// The backend is probed even while the circuit is open, so that a health
+    /// check reports when the backend recovers, and the probe does not count as
+    /// a call of the circuit.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend of the inner client is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+impl<C: SecretClient> CircuitBreakerSecretClient<C> {
+    /// Creates a new `CircuitBreakerSecretClient` wrapping the given client.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client calls are delegated to
+    /// * `failure_threshold` - The number of consecutive failures opening the circuit
+    /// * `reset_timeout` - The duration the circuit stays open before a trial call
+    ///
+    /// # Returns
+    ///
+    /// A new `CircuitBreakerSecretClient` with a closed circuit
+    pub fn new(
+        inner: C,
+        failure_threshold: u32,
+        reset_timeout: Duration,
+    ) -> CircuitBreakerSecretClient<C> {
+        CircuitBreakerSecretClient {
+            inner,
+            failure_threshold,
+            reset_timeout,
+            state: AtomicU8::new(CLOSED),
+            failures: AtomicU32::new(0),
+            opened_at: AtomicU64::new(0),
+            created_at: Instant::now(),
+        }
+    }
+
+    /// Returns the wrapped client.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the inner client
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Runs a call through the circuit and records its outcome.
+    fn call<T>(
+        &self,
+        call: impl FnOnce() -> Result<T, SecretsManagerError>,
+    ) -> Result<T, SecretsManagerError> {
+        let trial = self.admit()?;
+        let result = call();
+        self.record(&result, trial);
+        result
+    }
+
+    /// Decides whether a call may go through the circuit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the call is the trial call of a half-open circuit
+    /// * `Err(SecretsManagerError::CircuitOpen)` - If the circuit is open
+    fn admit(&self) -> Result<bool, SecretsManagerError> {
+        let trial = match self.state.load(Ordering::Acquire) {
+            CLOSED => false,
+            OPEN if self.elapsed_since_open() >= self.reset_timeout => {
+                // Only the caller winning the transition runs the trial call
+                if self
+                    .state
+                    .compare_exchange(OPEN, HALF_OPEN, Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+                {
+                    return Err(SecretsManagerError::CircuitOpen);
+                }
+                true
+            }
+            _ => return Err(SecretsManagerError::CircuitOpen),
+        };
+
+        Ok(trial)
+    }
+
+    /// Records the outcome of a call that went through the circuit.
+    fn record<T>(&self, result: &Result<T, SecretsManagerError>, trial: bool) {
+        match result {
+            Err(err) if err.is_retryable() => self.on_failure(trial),
+            _ => self.on_success(trial),
+        }
+    }
+
+    /// Closes the circuit after a trial call, or resets the failure count.
+    fn on_success(&self, trial: bool) {
+        self.failures.store(0, Ordering::Release);
+
+        if trial {
+            self.state.store(CLOSED, Ordering::Release);
+        }
+    }
+
+    /// Opens the circuit after a failed trial call or too many consecutive failures.
+    fn on_failure(&self, trial: bool) {
+        let failures = self.failures.fetch_add(1, Ordering::AcqRel) + 1;
+
+        if trial || failures >= self.failure_threshold {
+            warn!(failures = failures, "opening secret client circuit");
+
+            let now = self.created_at.elapsed().as_nanos() as u64;
+            self.opened_at.store(now, Ordering::Release);
+            self.state.store(OPEN, Ordering::Release);
+        }
+    }
+
+    /// Returns the time elapsed since the circuit was last opened.
+    fn elapsed_since_open(&self) -> Duration {
+        let opened_at = Duration::from_nanos(self.opened_at.load(Ordering::Acquire));
+        self.created_at.elapsed().saturating_sub(opened_at)
+    }
+}
@@ -7,12 +7,22 @@
 //! This trait provides the main abstraction for retrieving secrets regardless of the underlying
 //! implementation (AWS, fake, etc.).
 
-use crate::errors::SecretsManagerError;
+use crate::{SecretValue, errors::SecretsManagerError};
 use async_trait::async_trait;
 #[cfg(test)]
 use mockall::*;
 #[cfg(feature = "mocks")]
 use mockall::*;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    future::{self, Future},
+    pin::Pin,
+    sync::Arc,
+    task::Poll,
+};
+use tracing::error;
 
 /// Trait that defines the core functionality for retrieving secrets.
 ///
@@ -34,6 +44,39 @@ use mockall::*;
 /// let fake_client = FakeSecretClient::new();
 /// use_client(&fake_client);
 /// ```
+///
+/// References, `Arc` and `Box` pointers to a client, including `Arc<dyn SecretClient>`,
+/// implement the trait as well:
+///
+/// ```rust
+/// use secrets_manager::{FakeSecretClient, SecretClient};
+/// use std::sync::Arc;
+///
+/// fn use_client(client: impl SecretClient) -> bool {
+///     client.get_by_key("api-key").is_ok()
+/// }
+///
+/// let shared: Arc<dyn SecretClient> = Arc::new(FakeSecretClient::new());
+/// assert!(use_client(shared.clone()));
+///
+/// let boxed: Box<dyn SecretClient> = Box::new(FakeSecretClient::new());
+/// assert!(use_client(boxed));
+///
+/// let client = FakeSecretClient::new();
+/// assert!(use_client(&client));
+/// ```
+///
+/// A plain `HashMap<String, String>` or `HashMap<&str, &str>` is the simplest client
+/// for trivial tests and scripts:
+///
+/// ```rust
+/// use secrets_manager::SecretClient;
+/// use std::collections::HashMap;
+///
+/// let client = HashMap::from([("api-key", "value")]);
+/// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+/// assert!(client.get_by_key("db-pass").unwrap_err().is_not_found());
+/// ```
 #[cfg_attr(test, automock)]
 #[cfg_attr(feature = "mocks", automock)]
 #[async_trait]
@@ -46,7 +89,675 @@ pub trait SecretClient: Send + Sync {
     ///
     /// # Returns
     ///
-    /// * `Ok(String)` - The secret value as a string
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    // The attribute is left out of mocks, since `automock` copies it to an impl block
+    #[cfg_attr(
+        not(any(test, feature = "mocks")),
+        must_use = "a secret retrieval result should be used or its error handled"
+    )]
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError>;
+
+    /// Retrieves a secret value by its key, treating a missing secret as `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(SecretValue))` - The secret value
+    /// * `Ok(None)` - If the secret is not found
+    /// * `Err(SecretsManagerError)` - Any other error, forwarded unchanged
+    fn get_optional(&self, key: &str) -> Result<Option<SecretValue>, SecretsManagerError> {
+        match self.get_by_key(key) {
+            Ok(secret) => Ok(Some(secret)),
+            Err(SecretsManagerError::SecretNotFound { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Retrieves a secret value by its key, falling back to a default when it is missing.
+    ///
+    /// Only `SecretsManagerError::SecretNotFound` is replaced by the default. Hard
+    /// failures such as `InternalError` or `RequestFailure` are still returned, so
+    /// a broken backend is never silently masked by the default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    /// * `default` - The value returned when the secret is not found
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value, or `default` if the secret is not found
+    /// * `Err(SecretsManagerError)` - Any other error, forwarded unchanged
+    fn get_with_default(
+        &self,
+        key: &str,
+        default: &str,
+    ) -> Result<SecretValue, SecretsManagerError> {
+        Ok(self
+            .get_optional(key)?
+            .unwrap_or_else(|| SecretValue::from(default.to_owned())))
+    }
+
+    /// Checks whether a secret exists for the given key.
+    ///
+    /// The default implementation calls [`SecretClient::get_by_key`] and discards
+    /// the value; implementations that can probe their storage directly should
+    /// override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret can be retrieved, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.get_by_key(key).is_ok()
+    }
+
+    /// Lists the keys of all secrets available from this client.
+    ///
+    /// The default implementation returns `SecretsManagerError::NotSupported`
+    /// for backends that cannot enumerate their secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    /// * `Err(SecretsManagerError::NotSupported)` - If the backend cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Err(SecretsManagerError::NotSupported {})
+    }
+
+    /// Lists the keys of all secrets starting with the given prefix.
+    ///
+    /// The default implementation filters the result of [`SecretClient::list_keys`].
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix the returned keys start with, e.g. `db_`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The matching secret keys
+    /// * `Err(SecretsManagerError::NotSupported)` - If the backend cannot list its keys
+    fn keys_with_prefix(&self, prefix: &str) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self
+            .list_keys()?
+            .into_iter()
+            .filter(|key| key.starts_with(prefix))
+            .collect())
+    }
+
+    /// Retrieves all secrets available from this client.
+    ///
+    /// The default implementation calls [`SecretClient::list_keys`] and then
+    /// [`SecretClient::get_by_key`] for every key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - Every secret keyed by its key
+    /// * `Err(SecretsManagerError::NotSupported)` - If the backend cannot list its keys
+    /// * `Err(SecretsManagerError)` - If any secret couldn't be retrieved
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        self.list_keys()?
+            .into_iter()
+            .map(|key| {
+                let secret = self.get_by_key(&key)?;
+                Ok((key, secret))
+            })
+            .collect()
+    }
+
+    /// Retrieves a secret value by its key and substitutes its placeholders.
+    ///
+    /// Every `{{NAME}}` placeholder of the secret whose `NAME` is a key of `ctx`
+    /// is replaced by the matching value, e.g. `jdbc:postgresql://{{HOST}}:5432/{{DB}}`.
+    /// Placeholders missing from `ctx` are left untouched, and substituted values
+    /// are not themselves searched for placeholders.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    /// * `ctx` - The values of the placeholders, keyed by placeholder name
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value with its placeholders substituted
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient};
+    /// use std::collections::HashMap;
+    ///
+    /// let client = FakeSecretClient::builder()
+    ///     .set("db-url", "jdbc:postgresql://{{HOST}}:5432/{{DB}}")
+    ///     .build();
+    ///
+    /// let ctx = HashMap::from([("HOST", "db.internal"), ("DB", "orders")]);
+    /// let url = client.get_by_key_with_context("db-url", &ctx).unwrap();
+    ///
+    /// assert_eq!(&*url, "jdbc:postgresql://db.internal:5432/orders");
+    /// ```
+    // The explicit lifetimes are required for `automock` to handle the nested references
+    #[allow(clippy::needless_lifetimes)]
+    fn get_by_key_with_context<'a, 'b>(
+        &self,
+        key: &str,
+        ctx: &HashMap<&'a str, &'b str>,
+    ) -> Result<SecretValue, SecretsManagerError> {
+        let secret = self.get_by_key(key)?;
+
+        Ok(SecretValue::from(substitute(&secret, ctx)))
+    }
+
+    /// Retrieves a secret value by its key asynchronously.
+    ///
+    /// Backends that need I/O to serve a secret can override this method; the
+    /// default implementation delegates to [`SecretClient::get_by_key`]. Because
+    /// the trait is declared with `async_trait`, the method returns a boxed future
+    /// and remains callable through `dyn SecretClient`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.get_by_key(key)
+    }
+
+    /// Retrieves a secret value by its key as a shared `Arc<str>`.
+    ///
+    /// The returned value can be cloned cheaply across concurrent tasks. Unlike
+    /// `SecretValue`, it is not zeroed from memory when dropped. The default
+    /// implementation wraps the result of [`SecretClient::get_by_key`].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Arc<str>)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    fn get_arc_by_key(&self, key: &str) -> Result<Arc<str>, SecretsManagerError> {
+        Ok(Arc::from(&*self.get_by_key(key)?))
+    }
+
+    /// Retrieves a secret value by its key and parses it as an unsigned integer.
+    ///
+    /// Surrounding whitespace is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The parsed secret value
+    /// * `Err(SecretsManagerError::ParseError)` - If the value is not an unsigned integer
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient, errors::SecretsManagerError};
+    ///
+    /// let client = FakeSecretClient::builder()
+    ///     .set("port", "5432")
+    ///     .set("host", "db.internal")
+    ///     .build();
+    ///
+    /// assert_eq!(client.get_u64("port"), Ok(5432));
+    /// assert_eq!(
+    ///     client.get_u64("host"),
+    ///     Err(SecretsManagerError::ParseError {
+    ///         key: "host".to_string(),
+    ///         expected_type: "u64".into(),
+    ///     })
+    /// );
+    /// ```
+    fn get_u64(&self, key: &str) -> Result<u64, SecretsManagerError> {
+        let secret = self.get_by_key(key)?;
+
+        secret.trim().parse().map_err(|_| parse_error(key, "u64"))
+    }
+
+    /// Retrieves a secret value by its key and parses it as a boolean.
+    ///
+    /// `true` and `1` parse as `true`, `false` and `0` as `false`, ignoring case
+    /// and surrounding whitespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - The parsed secret value
+    /// * `Err(SecretsManagerError::ParseError)` - If the value is not a boolean
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    fn get_bool(&self, key: &str) -> Result<bool, SecretsManagerError> {
+        let secret = self.get_by_key(key)?;
+        let value = secret.trim();
+
+        if value == "1" || value.eq_ignore_ascii_case("true") {
+            Ok(true)
+        } else if value == "0" || value.eq_ignore_ascii_case("false") {
+            Ok(false)
+        } else {
+            Err(parse_error(key, "bool"))
+        }
+    }
+
+    /// Retrieves a secret value by its key and parses it as a floating point number.
+    ///
+    /// Surrounding whitespace is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The parsed secret value
+    /// * `Err(SecretsManagerError::ParseError)` - If the value is not a number
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    fn get_f64(&self, key: &str) -> Result<f64, SecretsManagerError> {
+        let secret = self.get_by_key(key)?;
+
+        secret.trim().parse().map_err(|_| parse_error(key, "f64"))
+    }
+
+    /// Retrieves a secret value by its key and deserializes it from JSON.
+    ///
+    /// This is useful when a single secret key stores a nested JSON object,
+    /// such as a database configuration struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The secret value deserialized into `T`
+    /// * `Err(SecretsManagerError::DeserializationError)` - If the value is not valid JSON for `T`
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    fn get_as<T: DeserializeOwned + 'static>(&self, key: &str) -> Result<T, SecretsManagerError>
+    where
+        Self: Sized,
+    {
+        let secret = self.get_by_key(key)?;
+
+        serde_json::from_str(&secret).map_err(|err| {
+            error!(error = err.to_string(), "error deserializing secret");
+            SecretsManagerError::DeserializationError(err.to_string())
+        })
+    }
+
+    /// Retrieves a secret value by its key as a JSON object.
+    ///
+    /// Unlike `get_as::<HashMap<String, String>>`, the nested values keep their
+    /// JSON type. Clients storing JSON documents, such as `AWSSecretClient`,
+    /// return nested objects directly; a string value is parsed as JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient, errors::SecretsManagerError};
+    ///
+    /// let client = FakeSecretClient::builder()
+    ///     .set("endpoints", r#"{"primary": "db-1.internal", "port": 5432}"#)
+    ///     .set("host", "db.internal")
+    ///     .build();
+    ///
+    /// let endpoints = client.get_json_object_by_key("endpoints").unwrap();
+    /// assert_eq!(endpoints["primary"], "db-1.internal");
+    /// assert_eq!(endpoints["port"], 5432);
+    ///
+    /// assert_eq!(
+    ///     client.get_json_object_by_key("host"),
+    ///     Err(SecretsManagerError::TypeMismatch {
+    ///         key: "host".to_string(),
+    ///         expected: "object".into(),
+    ///     })
+    /// );
+    /// ```
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        let secret = self.get_by_key(key)?;
+
+        json_object(key, Value::String(secret.into_string()))
+    }
+
+    /// Retrieves a secret value by its key as a JSON array.
+    ///
+    /// Clients storing JSON documents, such as `AWSSecretClient`, return nested
+    /// arrays directly; a string value is parsed as JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
     /// * `Err(SecretsManagerError)` - If the secret couldn't be retrieved
-    fn get_by_key(&self, key: &str) -> Result<String, SecretsManagerError>;
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient};
+    ///
+    /// let client = FakeSecretClient::builder()
+    ///     .set("allowed_ips", r#"["1.2.3.4", "5.6.7.8"]"#)
+    ///     .build();
+    ///
+    /// let allowed_ips = client.get_json_array_by_key("allowed_ips").unwrap();
+    /// assert_eq!(allowed_ips, ["1.2.3.4", "5.6.7.8"]);
+    /// ```
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        let secret = self.get_by_key(key)?;
+
+        json_array(key, Value::String(secret.into_string()))
+    }
+
+    /// Retrieves several secret values at once.
+    ///
+    /// Every key is looked up independently, so a failure for one key does not
+    /// prevent the remaining keys from being retrieved.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys identifying the secrets to retrieve
+    ///
+    /// # Returns
+    ///
+    /// A map from each requested key to the result of retrieving it
+    // The explicit lifetime is required for `automock` to handle the nested reference
+    #[allow(clippy::needless_lifetimes)]
+    fn get_many<'a>(
+        &self,
+        keys: &[&'a str],
+    ) -> HashMap<String, Result<SecretValue, SecretsManagerError>> {
+        keys.iter()
+            .map(|key| (key.to_string(), self.get_by_key(key)))
+            .collect()
+    }
+
+    /// Loads several secrets ahead of their first use, e.g. before serving traffic.
+    ///
+    /// Clients serving secrets from a cache override this method to fill it; the
+    /// default implementation does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys identifying the secrets to load
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If every secret was loaded
+    /// * `Err(SecretsManagerError)` - If some secrets couldn't be loaded
+    // The explicit lifetime is required for `automock` to handle the nested reference
+    #[allow(clippy::needless_lifetimes)]
+    async fn prefetch<'a>(&self, keys: &[&'a str]) -> Result<(), SecretsManagerError> {
+        let _ = keys;
+        Ok(())
+    }
+}
+
+/// Implements `SecretClient` for a pointer to a client by delegating to the pointee.
+///
+/// Every method a client may override is forwarded, so that the pointee's own
+/// implementation is used rather than the default one.
+macro_rules! delegate_secret_client {
+    ($pointer:ty) => {
+        #[async_trait]
+        impl<T: SecretClient + ?Sized> SecretClient for $pointer {
+            fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+                (**self).get_by_key(key)
+            }
+
+            fn contains_key(&self, key: &str) -> bool {
+                (**self).contains_key(key)
+            }
+
+            fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+                (**self).list_keys()
+            }
+
+            fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+                (**self).get_all()
+            }
+
+            async fn get_by_key_async(
+                &self,
+                key: &str,
+            ) -> Result<SecretValue, SecretsManagerError> {
+                (**self).get_by_key_async(key).await
+            }
+
+            fn get_arc_by_key(&self, key: &str) -> Result<Arc<str>, SecretsManagerError> {
+                (**self).get_arc_by_key(key)
+            }
+
+            fn get_json_object_by_key(
+                &self,
+                key: &str,
+            ) -> Result<Map<String, Value>, SecretsManagerError> {
+                (**self).get_json_object_by_key(key)
+            }
+
+            fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+                (**self).get_json_array_by_key(key)
+            }
+
+            async fn prefetch<'a>(&self, keys: &[&'a str]) -> Result<(), SecretsManagerError> {
+                (**self).prefetch(keys).await
+            }
+        }
+    };
+}
+
+// Borrowed, shared and boxed clients, including `Arc<dyn SecretClient>` and
+// `Box<dyn SecretClient>`, can be passed wherever an `impl SecretClient` is expected
+delegate_secret_client!(&T);
+delegate_secret_client!(Arc<T>);
+delegate_secret_client!(Box<T>);
+
+/// Implements `SecretClient` for a `HashMap` of secrets keyed by secret key.
+macro_rules! map_secret_client {
+    ($map:ty) => {
+        impl SecretClient for $map {
+            fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+                let Some(secret) = self.get(key) else {
+                    error!(key = key, "secret {} was not found", key);
+                    return Err(SecretsManagerError::SecretNotFound {
+                        key: key.to_string(),
+                    });
+                };
+
+                Ok(SecretValue::from(secret.to_string()))
+            }
+
+            fn contains_key(&self, key: &str) -> bool {
+                HashMap::contains_key(self, key)
+            }
+
+            fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+                Ok(self.keys().map(|key| key.to_string()).collect())
+            }
+
+            fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+                Ok(self
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), SecretValue::from(value.to_string())))
+                    .collect())
+            }
+        }
+    };
+}
+
+map_secret_client!(HashMap<String, String>);
+map_secret_client!(HashMap<&str, &str>);
+
+/// Polls the given futures concurrently until all of them are complete.
+///
+/// The futures run on the calling task, so they may borrow data, unlike the
+/// tasks of a `JoinSet`.
+///
+/// # Arguments
+///
+/// * `futures` - The futures to poll
+///
+/// # Returns
+///
+/// The outputs of the futures, in the order of the futures
+pub(crate) async fn join_all<F: Future + Unpin>(futures: Vec<F>) -> Vec<F::Output> {
+    let mut pending = futures.into_iter().map(Some).collect::<Vec<_>>();
+    let mut outputs = pending.iter().map(|_| None).collect::<Vec<_>>();
+
+    future::poll_fn(|cx| {
+        let mut complete = true;
+
+        for (future, output) in pending.iter_mut().zip(outputs.iter_mut()) {
+            let Some(polled) = future else {
+                continue;
+            };
+
+            match Pin::new(polled).poll(cx) {
+                Poll::Ready(value) => {
+                    *output = Some(value);
+                    *future = None;
+                }
+                Poll::Pending => complete = false,
+            }
+        }
+
+        match complete {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    })
+    .await;
+
+    outputs.into_iter().flatten().collect()
+}
+
+/// Builds the error reported when a secret cannot be parsed into a primitive type.
+fn parse_error(key: &str, expected_type: &'static str) -> SecretsManagerError {
+    error!(key = key, "secret {} is not a valid {}", key, expected_type);
+    SecretsManagerError::ParseError {
+        key: key.to_string(),
+        expected_type: expected_type.into(),
+    }
+}
+
+/// Converts a secret value into a JSON object, parsing it if it is a string.
+///
+/// # Arguments
+///
+/// * `key` - The key of the secret, for the error
+/// * `value` - The secret value
+///
+/// # Returns
+///
+/// * `Ok(Map<String, Value>)` - The JSON object
+/// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+pub(crate) fn json_object(
+    key: &str,
+    value: Value,
+) -> Result<Map<String, Value>, SecretsManagerError> {
+    match parse_json(value) {
+        Value::Object(object) => Ok(object),
+        _ => Err(type_mismatch(key, "object")),
+    }
+}
+
+/// Converts a secret value into a JSON array, parsing it if it is a string.
+///
+/// # Arguments
+///
+/// * `key` - The key of the secret, for the error
+/// * `value` - The secret value
+///
+/// # Returns
+///
+/// * `Ok(Vec<Value>)` - The elements of the JSON array
+/// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+pub(crate) fn json_array(key: &str, value: Value) -> Result<Vec<Value>, SecretsManagerError> {
+    match parse_json(value) {
+        Value::Array(array) => Ok(array),
+        _ => Err(type_mismatch(key, "array")),
+    }
+}
+
+/// Parses a string value holding a JSON document, leaving other values untouched.
+fn parse_json(value: Value) -> Value {
+    match value {
+        Value::String(secret) => serde_json::from_str(&secret).unwrap_or(Value::String(secret)),
+        value => value,
+    }
+}
+
+/// Builds the error reported when a secret is not of the requested JSON type.
+fn type_mismatch(key: &str, expected: &'static str) -> SecretsManagerError {
+    error!(key = key, "secret {} is not a json {}", key, expected);
+    SecretsManagerError::TypeMismatch {
+        key: key.to_string(),
+        expected: expected.into(),
+    }
+}
+
+/// Replaces the `{{NAME}}` placeholders of a template with the values of `ctx`.
+///
+/// # Arguments
+///
+/// * `template` - The text holding the placeholders
+/// * `ctx` - The values of the placeholders, keyed by placeholder name
+///
+/// # Returns
+///
+/// The text with every known placeholder substituted
+fn substitute(template: &str, ctx: &HashMap<&str, &str>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+
+        let name = &rest[start + 2..start + 2 + end];
+        output.push_str(&rest[..start]);
+
+        match ctx.get(name) {
+            Some(value) => output.push_str(value),
+            None => output.push_str(&rest[start..start + end + 4]),
+        }
+
+        rest = &rest[start + end + 4..];
+    }
+
+    output.push_str(rest);
+    output
 }
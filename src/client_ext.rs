@@ -0,0 +1,75 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Defines the `SecretClientExt` extension trait of `SecretClient`.
+//!
+//! The extension trait holds helpers built on top of the `SecretClient` methods,
+//! which every client gets through a blanket implementation and none overrides.
+
+use crate::{SecretClient, errors::SecretsManagerError};
+use std::collections::HashMap;
+use tracing::error;
+
+/// Extension trait adding helpers to every `SecretClient`.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{SecretClientExt, errors::SecretsManagerError};
+/// use std::collections::HashMap;
+///
+/// let client = HashMap::from([("api-key", "value"), ("db-pass", "secret")]);
+///
+/// let secrets = client.require_all(&["api-key", "db-pass"]).unwrap();
+/// assert_eq!(secrets["db-pass"], "secret");
+///
+/// let err = client.require_all(&["api-key", "db-user", "db-host"]).unwrap_err();
+/// assert_eq!(
+///     err.errors(),
+///     [
+///         SecretsManagerError::SecretNotFound { key: "db-user".to_string() },
+///         SecretsManagerError::SecretNotFound { key: "db-host".to_string() },
+///     ]
+/// );
+/// ```
+pub trait SecretClientExt: SecretClient {
+    /// Retrieves a fixed set of secrets that must all exist, e.g. during startup.
+    ///
+    /// Every key is retrieved, even after a failure, so that all the missing
+    /// secrets are reported at once rather than one per deployment.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys identifying the required secrets
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, String>)` - The value of every key
+    /// * `Err(SecretsManagerError::AggregateError)` - The errors of the keys that
+    ///   couldn't be retrieved, in the order of `keys`
+    fn require_all(&self, keys: &[&str]) -> Result<HashMap<String, String>, SecretsManagerError> {
+        let mut secrets = HashMap::with_capacity(keys.len());
+        let mut errors = Vec::new();
+
+        for key in keys {
+            match self.get_by_key(key) {
+                Err(err) => {
+                    error!(key = key, "required secret {} couldn't be retrieved", key);
+                    errors.push(err);
+                }
+                Ok(secret) => {
+                    secrets.insert(key.to_string(), secret.into_string());
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(SecretsManagerError::AggregateError(errors));
+        }
+
+        Ok(secrets)
+    }
+}
+
+impl<T: SecretClient + ?Sized> SecretClientExt for T {}
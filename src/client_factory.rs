@@ -0,0 +1,99 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Defines the `SecretClientFactory` trait unifying the builders of every backend.
+//!
+//! Each backend builder has its own configuration API, but once configured they
+//! can all be held as a `Box<dyn SecretClientFactory>`, so that the backend can be
+//! chosen at runtime and the client built later on.
+
+#[cfg(feature = "azure")]
+use crate::AzureKeyVaultClientBuilder;
+#[cfg(feature = "etcd")]
+use crate::EtcdSecretClientBuilder;
+#[cfg(feature = "gcp")]
+use crate::GcpSecretClientBuilder;
+#[cfg(feature = "k8s")]
+use crate::KubeSecretClientBuilder;
+#[cfg(feature = "redis")]
+use crate::RedisSecretClientBuilder;
+#[cfg(feature = "vault_client")]
+use crate::VaultSecretClientBuilder;
+use crate::{
+    AWSSecretClientBuilder, SecretClient, SecretClientBuilder, errors::SecretsManagerError,
+};
+use async_trait::async_trait;
+
+/// Trait implemented by the builders of every backend, building a boxed client.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{AWSSecretClientBuilder, SecretClientBuilder, SecretClientFactory};
+///
+/// fn factory(backend: &str) -> Box<dyn SecretClientFactory> {
+///     match backend {
+///         "aws" => Box::new(AWSSecretClientBuilder::new("infra/db".to_string())),
+///         _ => Box::new(SecretClientBuilder::new()),
+///     }
+/// }
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = factory("aws").build().await?;
+///     let password = client.get_by_key("password")?;
+///
+///     Ok(())
+/// }
+/// ```
+#[async_trait]
+pub trait SecretClientFactory: Send + Sync {
+    /// Builds the client configured by the builder.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Box<dyn SecretClient>)` - The built client
+    /// * `Err(SecretsManagerError)` - If the client couldn't be built
+    async fn build(&self) -> Result<Box<dyn SecretClient>, SecretsManagerError>;
+}
+
+/// Implements `SecretClientFactory` for a builder by boxing the client it builds.
+macro_rules! secret_client_factory {
+    ($builder:ty) => {
+        #[async_trait]
+        impl SecretClientFactory for $builder {
+            async fn build(&self) -> Result<Box<dyn SecretClient>, SecretsManagerError> {
+                Ok(Box::new(<$builder>::build(self).await?))
+            }
+        }
+    };
+    ($builder:ty, blocking) => {
+        #[async_trait]
+        impl SecretClientFactory for $builder {
+            async fn build(&self) -> Result<Box<dyn SecretClient>, SecretsManagerError> {
+                Ok(Box::new(<$builder>::build(self)?))
+            }
+        }
+    };
+}
+
+secret_client_factory!(AWSSecretClientBuilder);
+#[cfg(feature = "azure")]
+secret_client_factory!(AzureKeyVaultClientBuilder, blocking);
+#[cfg(feature = "etcd")]
+secret_client_factory!(EtcdSecretClientBuilder);
+#[cfg(feature = "gcp")]
+secret_client_factory!(GcpSecretClientBuilder);
+#[cfg(feature = "k8s")]
+secret_client_factory!(KubeSecretClientBuilder);
+#[cfg(feature = "redis")]
+secret_client_factory!(RedisSecretClientBuilder, blocking);
+#[cfg(feature = "vault_client")]
+secret_client_factory!(VaultSecretClientBuilder);
+
+#[async_trait]
+impl SecretClientFactory for SecretClientBuilder {
+    async fn build(&self) -> Result<Box<dyn SecretClient>, SecretsManagerError> {
+        SecretClientBuilder::build(self.clone()).await
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! `config` crate integration.
+//!
+//! This module implements `config::Source` for `AWSSecretClient`, so that its
+//! secrets can be merged with the other sources of a layered configuration.
+
+use crate::{AWSSecretClient, SecretValue};
+use config::{ConfigError, Map, Source, Value, ValueKind};
+use std::collections::HashMap;
+use tracing::error;
+
+/// Provides the cached secrets as configuration properties.
+///
+/// Every top-level secret becomes a property keyed by the secret key converted
+/// to snake_case, e.g. `db-password` and `DbPassword` both become `db_password`.
+/// Dots are kept, so `db.password` is nested under a `db` table as usual.
+///
+/// Nested JSON objects become tables, with their keys converted to snake_case
+/// as well, and arrays become arrays. Strings, numbers and booleans all surface
+/// as `Value::String`, numbers and booleans in their JSON representation. Keys
+/// converted to the same snake_case key, e.g. `db-password` and `dbPassword`,
+/// fail the collection rather than silently overwriting each other.
+///
+/// # Examples
+///
+/// ```rust
+/// use config::Config;
+/// use secrets_manager::AWSSecretClient;
+/// use serde_json::json;
+///
+/// let client = AWSSecretClient::try_from(json!({
+///     "db-password": "secret",
+///     "replica": { "hostName": "db-2.internal", "port": 5432 }
+/// }))
+/// .unwrap();
+/// let config = Config::builder().add_source(client).build().unwrap();
+///
+/// assert_eq!(config.get_string("db_password").unwrap(), "secret");
+/// assert_eq!(config.get_string("replica.host_name").unwrap(), "db-2.internal");
+/// assert_eq!(config.get_int("replica.port").unwrap(), 5432);
+///
+/// let client = AWSSecretClient::try_from(json!({ "db-password": "a", "dbPassword": "b" })).unwrap();
+/// assert!(Config::builder().add_source(client).build().is_err());
+/// ```
+impl Source for AWSSecretClient {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        let secrets = self
+            .loaded()
+            .map_err(|err| ConfigError::Foreign(Box::new(err)))?;
+        let origin = self.request.secret_id.clone();
+
+        let Some(secrets) = secrets.as_object() else {
+            return Ok(Map::new());
+        };
+
+        table(secrets, &origin, |key| self.env_override(key))
+    }
+}
+
+/// Converts a JSON object to a table with snake_case keys.
+///
+/// # Arguments
+///
+/// * `object` - The JSON object to convert
+/// * `origin` - The origin of the values, the id of the secret
+/// * `env_override` - The value overriding a key of the object, if any
+///
+/// # Returns
+///
+/// * `Ok(Map<String, Value>)` - The table of the converted values
+/// * `Err(ConfigError)` - If two keys are converted to the same snake_case key
+fn table(
+    object: &serde_json::Map<String, serde_json::Value>,
+    origin: &str,
+    env_override: impl Fn(&str) -> Option<SecretValue>,
+) -> Result<Map<String, Value>, ConfigError> {
+    let mut table = Map::new();
+    let mut sources: HashMap<String, &str> = HashMap::new();
+
+    for (key, value) in object {
+        let snake = snake_case(key);
+
+        if let Some(other) = sources.insert(snake.clone(), key) {
+            error!(
+                key = key,
+                other = other,
+                "secret keys {} and {} are both converted to {}",
+                other,
+                key,
+                snake
+            );
+            return Err(ConfigError::Message(format!(
+                "secret keys {other} and {key} are both converted to {snake}"
+            )));
+        }
+
+        let value = match env_override(key) {
+            Some(secret) => Value::new(
+                Some(&origin.to_string()),
+                ValueKind::String(secret.into_string()),
+            ),
+            None => value_of(value, origin)?,
+        };
+        table.insert(snake, value);
+    }
+
+    Ok(table)
+}
+
+/// Converts a JSON value to a configuration value.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value to convert
+/// * `origin` - The origin of the value, the id of the secret
+///
+/// # Returns
+///
+/// * `Ok(Value)` - The configuration value
+/// * `Err(ConfigError)` - If two keys of a nested object are converted to the same snake_case key
+fn value_of(value: &serde_json::Value, origin: &str) -> Result<Value, ConfigError> {
+    let kind = match value {
+        serde_json::Value::Null => ValueKind::Nil,
+        serde_json::Value::String(value) => ValueKind::String(value.clone()),
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+            ValueKind::String(value.to_string())
+        }
+        serde_json::Value::Array(values) => ValueKind::Array(
+            values
+                .iter()
+                .map(|value| value_of(value, origin))
+                .collect::<Result<_, _>>()?,
+        ),
+        serde_json::Value::Object(object) => ValueKind::Table(table(object, origin, |_| None)?),
+    };
+
+    Ok(Value::new(Some(&origin.to_string()), kind))
+}
+
+/// Converts a key to snake_case, leaving its dots untouched.
+///
+/// # Arguments
+///
+/// * `key` - The key in kebab-case, camelCase, PascalCase or SCREAMING_SNAKE_CASE
+///
+/// # Returns
+///
+/// The lowercase key with words separated by underscores
+fn snake_case(key: &str) -> String {
+    let mut snake = String::with_capacity(key.len() + 4);
+    let mut previous: Option<char> = None;
+
+    for c in key.chars() {
+        match c {
+            '-' | ' ' => snake.push('_'),
+            c if c.is_uppercase() => {
+                if previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+                    snake.push('_');
+                }
+                snake.extend(c.to_lowercase());
+            }
+            c => snake.push(c),
+        }
+
+        previous = Some(c);
+    }
+
+    snake
+}
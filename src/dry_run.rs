@@ -0,0 +1,91 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Dry-run validation of required secrets.
+//!
+//! This module provides the `DryRunSecretClient` which checks that the secrets an
+//! application requires exist, without ever reading their values, so that CI
+//! pipelines can validate a deployment without risking leaking secrets in logs.
+
+use crate::{SecretClient, errors::SecretsManagerError};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Client validating that required secrets exist without returning their values.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{DryRunSecretClient, SecretClient};
+/// use std::{collections::HashMap, sync::Arc};
+///
+/// let inner: Arc<dyn SecretClient> = Arc::new(HashMap::from([
+///     ("api-key".to_string(), "value".to_string()),
+/// ]));
+///
+/// let client = DryRunSecretClient::new(inner.clone(), &["api-key"]);
+/// assert!(client.validate_all().is_ok());
+///
+/// let client = DryRunSecretClient::new(inner, &["api-key", "db-pass", "db-user"]);
+/// let err = client.validate_all().unwrap_err();
+/// assert_eq!(err.errors().len(), 2);
+/// assert!(err.errors().iter().all(|err| err.is_not_found()));
+/// ```
+pub struct DryRunSecretClient {
+    /// The client the keys are checked against
+    inner: Arc<dyn SecretClient>,
+    /// The keys that must exist
+    required_keys: Vec<String>,
+}
+
+impl DryRunSecretClient {
+    /// Creates a new `DryRunSecretClient` checking the given keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client the keys are checked against
+    /// * `required_keys` - The keys that must exist
+    ///
+    /// # Returns
+    ///
+    /// A new `DryRunSecretClient`
+    pub fn new(inner: Arc<dyn SecretClient>, required_keys: &[&str]) -> DryRunSecretClient {
+        DryRunSecretClient {
+            inner,
+            required_keys: required_keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+
+    /// Checks that every required key exists in the inner client.
+    ///
+    /// Only `contains_key` is called on the inner client, so no secret value is
+    /// ever read. Every key is checked, even after a missing one is found.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If every required key exists
+    /// * `Err(SecretsManagerError::AggregateError)` - A `SecretNotFound` error for every missing key
+    pub fn validate_all(&self) -> Result<(), SecretsManagerError> {
+        let errors: Vec<SecretsManagerError> = self
+            .required_keys
+            .iter()
+            .filter(|key| !self.inner.contains_key(key))
+            .map(|key| {
+                error!(key = key, "required secret {} was not found", key);
+                SecretsManagerError::SecretNotFound { key: key.clone() }
+            })
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(SecretsManagerError::AggregateError(errors));
+        }
+
+        info!(
+            count = self.required_keys.len(),
+            "all required secrets were found"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Environment variable secret client implementation.
+//!
+//! This module provides the `EnvSecretClient` which implements the `SecretClient` trait
+//! by reading secrets from the process environment. It needs no AWS credentials and
+//! is intended for local development.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use std::env::{self, VarError};
+use tracing::error;
+
+/// Client for reading secrets from environment variables.
+///
+/// Listing keys is not supported, since the environment holds many variables
+/// that are not secrets.
+///
+/// An optional prefix is prepended to every key before the variable is read,
+/// so `get_by_key("DB_PASS")` on a client created with
+/// `EnvSecretClient::with_prefix("MY_APP_")` reads `MY_APP_DB_PASS`.
+#[derive(Default)]
+pub struct EnvSecretClient {
+    /// The prefix prepended to every key
+    prefix: String,
+}
+
+impl SecretClient for EnvSecretClient {
+    /// Retrieves a secret from the environment variable named after its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The value of the environment variable
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the variable is not set
+    /// * `Err(SecretsManagerError::InternalError)` - If the variable is not valid unicode
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let name = format!("{}{}", self.prefix, key);
+
+        match env::var(&name) {
+            Err(VarError::NotPresent) => {
+                error!(key = name, "secret {} was not found", name);
+                Err(SecretsManagerError::SecretNotFound { key: name })
+            }
+            Err(VarError::NotUnicode(_)) => {
+                error!(key = name, "secret {} is not valid unicode", name);
+                Err(SecretsManagerError::internal(format!(
+                    "secret {name} is not valid unicode"
+                )))
+            }
+            Ok(v) => Ok(SecretValue::from(v)),
+        }
+    }
+}
+
+impl EnvSecretClient {
+    /// Creates a new `EnvSecretClient` that reads variables named exactly after the key.
+    ///
+    /// # Returns
+    ///
+    /// A new `EnvSecretClient`
+    pub fn new() -> EnvSecretClient {
+        EnvSecretClient::default()
+    }
+
+    /// Creates a new `EnvSecretClient` that prepends a prefix to every key.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix prepended to every key, e.g. `MY_APP_`
+    ///
+    /// # Returns
+    ///
+    /// A new `EnvSecretClient`
+    pub fn with_prefix(prefix: &str) -> EnvSecretClient {
+        EnvSecretClient {
+            prefix: prefix.to_string(),
+        }
+    }
+}
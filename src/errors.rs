@@ -7,27 +7,346 @@
 //! This module defines the various error types that can occur when working with
 //! secrets and AWS Secrets Manager.
 
+use aws_sdk_secretsmanager::error::{DisplayErrorContext, ProvideErrorMetadata, SdkError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt};
 use thiserror::Error;
 
+/// The message of the underlying error that caused a `SecretsManagerError`.
+///
+/// The original error is kept as a message so that `SecretsManagerError` stays
+/// `Clone`, comparable and serializable, while still being reported as the
+/// `source` of the error.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[error("{0}")]
+pub struct ErrorCause(pub String);
+
 /// Represents errors that can occur during secret management operations.
 ///
 /// This enum provides specific error variants for different failure scenarios
 /// when interacting with secrets and the AWS Secrets Manager service.
-#[derive(Error, Debug, PartialEq, Eq)]
+///
+/// The enum is `#[non_exhaustive]`: new variants may be added in minor releases,
+/// so a `match` outside this crate needs a wildcard arm. Prefer the
+/// [`SecretsManagerError::is_retryable`] and [`SecretsManagerError::is_not_found`]
+/// helpers over matching on the variants.
+///
+/// With the `serde` feature enabled, errors can be serialized to cross process
+/// or network boundaries:
+///
+/// ```rust
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use secrets_manager::errors::SecretsManagerError;
+///
+/// let errors = [
+///     SecretsManagerError::internal("lock was poisoned"),
+///     SecretsManagerError::request_failure("connection refused"),
+///     SecretsManagerError::Timeout,
+///     SecretsManagerError::ThrottlingError,
+///     SecretsManagerError::SecretNotFound {
+///         key: "api-key".to_string(),
+///     },
+///     SecretsManagerError::AwsSecretWasNotFound {
+///         secret_id: "infra/db".to_string(),
+///     },
+///     SecretsManagerError::NotSupported,
+///     SecretsManagerError::DeserializationError("missing field".to_string()),
+///     SecretsManagerError::ParseError {
+///         key: "port".to_string(),
+///         expected_type: "u64".into(),
+///     },
+///     SecretsManagerError::TypeMismatch {
+///         key: "endpoints".to_string(),
+///         expected: "object".into(),
+///     },
+///     SecretsManagerError::NotYetLoaded,
+///     SecretsManagerError::CircuitOpen,
+///     SecretsManagerError::RateLimited { retry_after_ms: 250 },
+///     SecretsManagerError::BudgetExhausted {
+///         key: "api-key".to_string(),
+///     },
+///     SecretsManagerError::BackendError {
+///         message: "WRONGTYPE".to_string(),
+///     },
+///     SecretsManagerError::AggregateError(vec![
+///         SecretsManagerError::Timeout,
+///         SecretsManagerError::NotSupported,
+///     ]),
+/// ];
+///
+/// for error in errors {
+///     let json = serde_json::to_string(&error).unwrap();
+///     let decoded: SecretsManagerError = serde_json::from_str(&json).unwrap();
+///     assert_eq!(decoded, error);
+/// }
+///
+/// let json = serde_json::to_string(&SecretsManagerError::SecretNotFound {
+///     key: "api-key".to_string(),
+/// })
+/// .unwrap();
+/// assert_eq!(json, r#"{"SecretNotFound":{"key":"api-key"}}"#);
+/// # }
+/// ```
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum SecretsManagerError {
     /// An internal error occurred in the secrets manager
     #[error("internal error")]
-    InternalError,
+    InternalError {
+        /// The error that caused the failure
+        #[source]
+        cause: ErrorCause,
+    },
 
     /// Failed to send a request to the secrets service
     #[error("failure to send request")]
-    RequestFailure,
+    RequestFailure {
+        /// The error reported when sending the request
+        #[source]
+        cause: ErrorCause,
+    },
+
+    /// The request to the secrets service timed out
+    #[error("request timed out")]
+    Timeout,
+
+    /// The secrets service throttled the request
+    #[error("request was throttled")]
+    ThrottlingError,
 
     /// The requested secret was not found in the local cache
-    #[error("secret not found")]
-    SecretNotFound,
+    #[error("secret {key} not found")]
+    SecretNotFound {
+        /// The key that was looked up
+        key: String,
+    },
 
     /// The requested secret was not found in AWS Secrets Manager
-    #[error("aws secret was not found")]
-    AwsSecretWasNotFound,
+    #[error("aws secret {secret_id} was not found")]
+    AwsSecretWasNotFound {
+        /// The name/id of the AWS secret that was requested
+        secret_id: String,
+    },
+
+    /// The operation is not supported by the secret backend
+    #[error("operation not supported")]
+    NotSupported,
+
+    /// The secret value could not be deserialized into the requested type
+    #[error("failure to deserialize secret: {0}")]
+    DeserializationError(String),
+
+    /// The secret value could not be parsed into the requested primitive type
+    #[error("failure to parse secret {key} as {expected_type}")]
+    ParseError {
+        /// The key of the secret that was parsed
+        key: String,
+        /// The name of the requested type, e.g. `u64`
+        expected_type: Cow<'static, str>,
+    },
+
+    /// The secret value is not the JSON type requested by a typed accessor
+    #[error("secret {key} is not a json {expected}")]
+    TypeMismatch {
+        /// The key of the secret that was retrieved
+        key: String,
+        /// The name of the requested JSON type, e.g. `object`
+        expected: Cow<'static, str>,
+    },
+
+    /// The secrets of a lazily built client are still being retrieved
+    #[error("secrets are not loaded yet")]
+    NotYetLoaded,
+
+    /// The circuit breaker is open and the secret backend was not called
+    #[error("circuit breaker is open")]
+    CircuitOpen,
+
+    /// The rate limit was exceeded and the secret backend was not called
+    #[error("rate limit exceeded, retry after {retry_after_ms}ms")]
+    RateLimited {
+        /// How long to wait before the call is allowed, in milliseconds
+        retry_after_ms: u64,
+    },
+
+    /// The secret failed too many consecutive times and the inner client was not called
+    #[error("retry budget of secret {key} is exhausted")]
+    BudgetExhausted {
+        /// The key that was looked up
+        key: String,
+    },
+
+    /// The secret backend reported an error with no more specific variant
+    #[error("backend error: {message}")]
+    BackendError {
+        /// The error reported by the backend
+        message: String,
+    },
+
+    /// Several operations of a bulk operation failed
+    ///
+    /// The inner errors are returned by [`SecretsManagerError::errors`].
+    #[error("{} errors: {}", .0.len(), join_errors(.0))]
+    AggregateError(Vec<SecretsManagerError>),
+}
+
+impl SecretsManagerError {
+    /// Creates an `InternalError` caused by the given error.
+    ///
+    /// # Arguments
+    ///
+    /// * `cause` - The error that caused the failure
+    ///
+    /// # Returns
+    ///
+    /// A new `SecretsManagerError::InternalError`
+    pub fn internal(cause: impl fmt::Display) -> SecretsManagerError {
+        SecretsManagerError::InternalError {
+            cause: ErrorCause(cause.to_string()),
+        }
+    }
+
+    /// Creates a `RequestFailure` caused by the given error.
+    ///
+    /// # Arguments
+    ///
+    /// * `cause` - The error reported when sending the request
+    ///
+    /// # Returns
+    ///
+    /// A new `SecretsManagerError::RequestFailure`
+    pub fn request_failure(cause: impl fmt::Display) -> SecretsManagerError {
+        SecretsManagerError::RequestFailure {
+            cause: ErrorCause(cause.to_string()),
+        }
+    }
+
+    /// Converts an error of the AWS SDK into the matching `SecretsManagerError`.
+    ///
+    /// Timeouts become `Timeout` and throttled requests `ThrottlingError`; any
+    /// other error becomes a `RequestFailure` carrying the full SDK error message.
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - The error returned by the AWS SDK
+    ///
+    /// # Returns
+    ///
+    /// The matching `SecretsManagerError`
+    pub fn from_aws_error<E, R>(err: &SdkError<E, R>) -> SecretsManagerError
+    where
+        E: ProvideErrorMetadata + std::error::Error + 'static,
+        R: fmt::Debug,
+    {
+        match err {
+            SdkError::TimeoutError(_) => SecretsManagerError::Timeout,
+            err if err.code() == Some("ThrottlingException") => {
+                SecretsManagerError::ThrottlingError
+            }
+            err => SecretsManagerError::request_failure(DisplayErrorContext(err)),
+        }
+    }
+
+    /// Returns the errors an error is made of.
+    ///
+    /// # Returns
+    ///
+    /// The inner errors of an `AggregateError`, or the error itself otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::errors::SecretsManagerError;
+    ///
+    /// let err = SecretsManagerError::AggregateError(vec![
+    ///     SecretsManagerError::Timeout,
+    ///     SecretsManagerError::ThrottlingError,
+    /// ]);
+    /// assert_eq!(err.errors().len(), 2);
+    /// assert_eq!(err.to_string(), "2 errors: request timed out; request was throttled");
+    ///
+    /// assert_eq!(SecretsManagerError::Timeout.errors(), [SecretsManagerError::Timeout]);
+    /// ```
+    pub fn errors(&self) -> &[SecretsManagerError] {
+        match self {
+            SecretsManagerError::AggregateError(errors) => errors,
+            err => std::slice::from_ref(err),
+        }
+    }
+
+    /// Returns whether the failed operation may succeed if attempted again.
+    ///
+    /// Request failures, timeouts, throttling, exceeded rate limits and secrets
+    /// still being loaded lazily are transient; missing secrets, internal errors
+    /// and unsupported operations are not. An open circuit and an exhausted retry
+    /// budget are not retryable either, since retrying is what they prevent. An
+    /// aggregate error is retryable only if all of its errors are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::errors::SecretsManagerError;
+    ///
+    /// assert!(SecretsManagerError::Timeout.is_retryable());
+    /// assert!(!SecretsManagerError::internal("lock was poisoned").is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SecretsManagerError::RequestFailure { .. }
+            | SecretsManagerError::Timeout
+            | SecretsManagerError::ThrottlingError
+            | SecretsManagerError::RateLimited { .. }
+            | SecretsManagerError::NotYetLoaded => true,
+            SecretsManagerError::InternalError { .. }
+            | SecretsManagerError::SecretNotFound { .. }
+            | SecretsManagerError::AwsSecretWasNotFound { .. }
+            | SecretsManagerError::NotSupported
+            | SecretsManagerError::DeserializationError(_)
+            | SecretsManagerError::ParseError { .. }
+            | SecretsManagerError::TypeMismatch { .. }
+            | SecretsManagerError::CircuitOpen
+            | SecretsManagerError::BudgetExhausted { .. }
+            | SecretsManagerError::BackendError { .. } => false,
+            SecretsManagerError::AggregateError(errors) => {
+                errors.iter().all(SecretsManagerError::is_retryable)
+            }
+        }
+    }
+
+    /// Returns whether the requested secret does not exist.
+    ///
+    /// Both a key missing from a client and an AWS secret missing from Secrets
+    /// Manager are reported as not found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::errors::SecretsManagerError;
+    ///
+    /// let err = SecretsManagerError::SecretNotFound {
+    ///     key: "api-key".to_string(),
+    /// };
+    /// assert!(err.is_not_found());
+    /// assert!(!SecretsManagerError::Timeout.is_not_found());
+    /// ```
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            SecretsManagerError::SecretNotFound { .. }
+                | SecretsManagerError::AwsSecretWasNotFound { .. }
+        )
+    }
+}
+
+/// Joins the messages of the errors of an `AggregateError`.
+fn join_errors(errors: &[SecretsManagerError]) -> String {
+    errors
+        .iter()
+        .map(|err| err.to_string())
+        .collect::<Vec<_>>()
+        .join("; ")
 }
@@ -0,0 +1,144 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! etcd secret client implementation.
+//!
+//! This module provides the `EtcdSecretClient` which implements the `SecretClient` trait
+//! for retrieving secrets stored as etcd key-value pairs.
+
+use crate::{SecretClient, SecretValue, blocking::block_on, errors::SecretsManagerError};
+use async_trait::async_trait;
+use etcd_client::{Client, Error, GetOptions};
+use tonic::Code;
+use tracing::error;
+
+/// Client for accessing secrets stored as etcd key-value pairs.
+///
+/// Nothing is cached: every call issues a request to etcd. Calling the
+/// synchronous methods blocks the current thread, which requires a
+/// multi-threaded `tokio` runtime; prefer `get_by_key_async` from
+/// asynchronous code.
+pub struct EtcdSecretClient {
+    /// The etcd client requests are sent with
+    pub(crate) client: Client,
+    /// The prefix of the keys reported by `list_keys`
+    pub(crate) prefix: String,
+}
+
+#[async_trait]
+impl SecretClient for EtcdSecretClient {
+    /// Retrieves a secret by its etcd key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The etcd key of the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the key does not exist
+    /// * `Err(SecretsManagerError::NotSupported)` - If not called from a multi-threaded
+    ///   `tokio` runtime
+    /// * `Err(SecretsManagerError)` - If the request failed
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        block_on(key, self.fetch(key))?
+    }
+
+    /// Lists the etcd keys starting with the configured prefix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    /// * `Err(SecretsManagerError)` - If the request failed
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        block_on(&self.prefix, self.fetch_keys())?
+    }
+
+    /// Retrieves a secret by its etcd key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The etcd key of the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the key does not exist
+    /// * `Err(SecretsManagerError)` - If the request failed
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.fetch(key).await
+    }
+}
+
+impl EtcdSecretClient {
+    /// Fetches the value of a key from etcd.
+    async fn fetch(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        // The client is a cheap handle over a shared channel, cloned to get a mutable one
+        let response = self.client.clone().get(key, None).await.map_err(|err| {
+            error!(error = err.to_string(), "failure send request to etcd");
+            map_error(&err)
+        })?;
+
+        let Some(kv) = response.kvs().first() else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        match kv.value_str() {
+            Err(err) => {
+                error!(key = key, "secret {} is not valid UTF-8", key);
+                Err(SecretsManagerError::DeserializationError(err.to_string()))
+            }
+            Ok(value) => Ok(SecretValue::from(value.to_string())),
+        }
+    }
+
+    /// Fetches the keys starting with the configured prefix from etcd.
+    async fn fetch_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        let options = GetOptions::new().with_prefix().with_keys_only();
+
+        let response = self
+            .client
+            .clone()
+            .get(self.prefix.as_str(), Some(options))
+            .await
+            .map_err(|err| {
+                error!(error = err.to_string(), "failure send request to etcd");
+                map_error(&err)
+            })?;
+
+        response
+            .kvs()
+            .iter()
+            .map(|kv| {
+                kv.key_str()
+                    .map(str::to_string)
+                    .map_err(|err| SecretsManagerError::DeserializationError(err.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// Maps an etcd error to the matching `SecretsManagerError`.
+pub(crate) fn map_error(err: &Error) -> SecretsManagerError {
+    match err {
+        Error::GRpcStatus(status) => match status.code() {
+            Code::DeadlineExceeded => SecretsManagerError::Timeout,
+            Code::ResourceExhausted => SecretsManagerError::ThrottlingError,
+            Code::Unavailable => SecretsManagerError::request_failure(status.message()),
+            _ => SecretsManagerError::BackendError {
+                message: status.message().to_string(),
+            },
+        },
+        Error::IoError(_) | Error::TransportError(_) => SecretsManagerError::request_failure(err),
+        Error::InvalidArgs(_) | Error::InvalidUri(_) | Error::EndpointError(_) => {
+            SecretsManagerError::internal(err)
+        }
+        _ => SecretsManagerError::BackendError {
+            message: err.to_string(),
+        },
+    }
+}
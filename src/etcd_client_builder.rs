@@ -0,0 +1,97 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! etcd secret client builder module.
+//!
+//! This module provides the `EtcdSecretClientBuilder` for constructing instances of
+//! `EtcdSecretClient` with appropriate configuration.
+
+use crate::{EtcdSecretClient, errors::SecretsManagerError, etcd_client::map_error};
+use etcd_client::{Client, ConnectOptions, TlsOptions};
+use tracing::error;
+
+/// Builder for etcd secret client instances.
+///
+/// This builder facilitates the creation of `EtcdSecretClient` instances by
+/// connecting to the etcd cluster.
+#[derive(Default)]
+pub struct EtcdSecretClientBuilder {
+    /// The URL of the etcd endpoint, e.g. `http://127.0.0.1:2379`
+    endpoint: String,
+    /// The prefix of the keys reported by `list_keys`
+    prefix: String,
+    /// The TLS configuration, if the endpoint uses `https`
+    tls: Option<TlsOptions>,
+}
+
+impl EtcdSecretClientBuilder {
+    /// Creates a new builder for the given etcd endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The URL of the etcd endpoint, e.g. `http://127.0.0.1:2379`
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `EtcdSecretClientBuilder`
+    pub fn new(endpoint: &str) -> EtcdSecretClientBuilder {
+        EtcdSecretClientBuilder {
+            endpoint: endpoint.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Restricts the keys reported by `list_keys` to those starting with a prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of the listed keys, e.g. `/secrets/payments/`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the prefix configured
+    pub fn prefix(mut self, prefix: &str) -> EtcdSecretClientBuilder {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Connects to the endpoint over TLS, which requires an `https` endpoint URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `tls` - The TLS configuration, e.g. the CA certificate and client identity
+    ///
+    /// # Returns
+    ///
+    /// The builder with TLS configured
+    pub fn with_tls(mut self, tls: TlsOptions) -> EtcdSecretClientBuilder {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Builds and returns an `EtcdSecretClient` instance.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EtcdSecretClient)` - If the connection was successfully established
+    /// * `Err(SecretsManagerError)` - If the endpoint is invalid or unreachable
+    pub async fn build(&self) -> Result<EtcdSecretClient, SecretsManagerError> {
+        let options = self
+            .tls
+            .clone()
+            .map(|tls| ConnectOptions::new().with_tls(tls));
+
+        let client = Client::connect([self.endpoint.as_str()], options)
+            .await
+            .map_err(|err| {
+                error!(error = err.to_string(), "failure to connect to etcd");
+                map_error(&err)
+            })?;
+
+        Ok(EtcdSecretClient {
+            client,
+            prefix: self.prefix.clone(),
+        })
+    }
+}
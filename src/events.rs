@@ -0,0 +1,297 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Secret lifecycle events.
+//!
+//! This module defines the `SecretEvent` emitted to an event sink, a `tokio`
+//! unbounded channel, when secrets are fetched, missing, served from a cache,
+//! rotated or failing, so that the events can be forwarded to an observability
+//! platform such as CloudWatch or Datadog.
+
+use crate::{HealthCheckableSecretClient, SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, Ordering},
+    time::SystemTime,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::warn;
+
+/// The kind of a secret lifecycle event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SecretEventKind {
+    /// The secret was retrieved from the backend
+    Fetched,
+    /// The secret does not exist in the backend
+    NotFound,
+    /// The secret was served from a cache
+    CacheHit,
+    /// The secret changed since it was last retrieved
+    Rotated,
+    /// The secret could not be retrieved; the error is in the `error` metadata
+    Error,
+}
+
+/// An event of the lifecycle of a secret.
+///
+/// Events never carry secret values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretEvent {
+    /// What happened to the secret
+    pub kind: SecretEventKind,
+    /// The key of the secret
+    pub key: String,
+    /// When the event happened
+    pub timestamp: SystemTime,
+    /// Additional details of the event, e.g. the `error` of an `Error` event
+    pub metadata: HashMap<String, String>,
+}
+
+impl SecretEvent {
+    /// Creates an event happening now, without metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - What happened to the secret
+    /// * `key` - The key of the secret
+    ///
+    /// # Returns
+    ///
+    /// A new `SecretEvent`
+    pub fn new(kind: SecretEventKind, key: &str) -> SecretEvent {
+        SecretEvent {
+            kind,
+            key: key.to_string(),
+            timestamp: SystemTime::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Creates the event reporting the outcome of a secret retrieval.
+    pub(crate) fn from_result<T>(
+        key: &str,
+        result: &Result<T, SecretsManagerError>,
+    ) -> SecretEvent {
+        match result {
+            Ok(_) => SecretEvent::new(SecretEventKind::Fetched, key),
+            Err(err) if err.is_not_found() => SecretEvent::new(SecretEventKind::NotFound, key),
+            Err(err) => SecretEvent {
+                metadata: HashMap::from([("error".to_string(), err.to_string())]),
+                ..SecretEvent::new(SecretEventKind::Error, key)
+            },
+        }
+    }
+}
+
+/// The sink events are sent to, which stops emitting once its receiver is dropped.
+pub(crate) struct EventSink {
+    /// The sender the events are sent to
+    sender: UnboundedSender<SecretEvent>,
+    /// Whether the receiver was dropped
+    closed: AtomicBool,
+}
+
+impl EventSink {
+    /// Creates a new `EventSink` sending the events to the given sender.
+    pub(crate) fn new(sender: UnboundedSender<SecretEvent>) -> EventSink {
+        EventSink {
+            sender,
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Sends an event, unless the receiver was dropped.
+    ///
+    /// A warning is logged the first time the receiver is found dropped, after
+    /// which events are discarded silently.
+    pub(crate) fn emit(&self, event: SecretEvent) {
+        if self.closed.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if self.sender.send(event).is_err() && !self.closed.swap(true, Ordering::Relaxed) {
+            warn!("secret event receiver was dropped, no more events are emitted");
+        }
+    }
+}
+
+/// Client that emits a `Fetched`, `NotFound` or `Error` event for every secret
+/// retrieved from an inner client.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{EventSecretClient, SecretClient, SecretEventKind};
+/// use std::collections::HashMap;
+/// use tokio::sync::mpsc;
+///
+/// let (sender, mut receiver) = mpsc::unbounded_channel();
+/// let inner = HashMap::from([("api-key", "value")]);
+/// let client = EventSecretClient::new(inner, sender);
+///
+/// let _ = client.get_by_key("api-key");
+/// let _ = client.get_by_key("db-pass");
+///
+/// assert_eq!(receiver.try_recv().unwrap().kind, SecretEventKind::Fetched);
+/// assert_eq!(receiver.try_recv().unwrap().kind, SecretEventKind::NotFound);
+///
+/// // Once the receiver is dropped, events are discarded
+/// drop(receiver);
+/// assert!(client.get_by_key("api-key").is_ok());
+/// ```
+pub struct EventSecretClient<C: SecretClient> {
+    /// The client secrets are retrieved from
+    inner: C,
+    /// The sink the events are sent to
+    sink: EventSink,
+}
+
+#[async_trait]
+impl<C: SecretClient> SecretClient for EventSecretClient<C> {
+    /// Retrieves a secret from the inner client and emits the outcome as an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let result = self.inner.get_by_key(key);
+        self.sink.emit(SecretEvent::from_result(key, &result));
+        result
+    }
+
+    /// Checks whether the inner client holds the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is present, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.inner.contains_key(key)
+    }
+
+    /// Lists the keys available from the inner client.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys reported by the inner client
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.inner.list_keys()
+    }
+
+    /// Retrieves every secret from the inner client and emits a `Fetched` event
+    /// for each of them.
+    ///
+    /// A failure is not tied to a single key, so it is emitted as one `Error`
+    /// event with an empty key.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - Every secret keyed by its key
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secrets
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        let result = self.inner.get_all();
+
+        match &result {
+            Ok(secrets) => secrets.keys().for_each(|key| {
+                self.sink
+                    .emit(SecretEvent::new(SecretEventKind::Fetched, key))
+            }),
+            Err(_) => self.sink.emit(SecretEvent::from_result("", &result)),
+        }
+
+        result
+    }
+
+    /// Retrieves a secret asynchronously from the inner client and emits the outcome as an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let result = self.inner.get_by_key_async(key).await;
+        self.sink.emit(SecretEvent::from_result(key, &result));
+        result
+    }
+
+    /// Retrieves a secret from the inner client as a JSON object and emits the outcome as an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        let result = self.inner.get_json_object_by_key(key);
+        self.sink.emit(SecretEvent::from_result(key, &result));
+        result
+    }
+
+    /// Retrieves a secret from the inner client as a JSON array and emits the outcome as an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        let result = self.inner.get_json_array_by_key(key);
+        self.sink.emit(SecretEvent::from_result(key, &result));
+        result
+    }
+}
+
+#[async_trait]
+impl<C: HealthCheckableSecretClient> HealthCheckableSecretClient for EventSecretClient<C> {
+    /// Checks whether the backend of the inner client is reachable.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend of the inner client is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+impl<C: SecretClient> EventSecretClient<C> {
+    /// Creates a new `EventSecretClient` wrapping the given client.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client secrets are retrieved from
+    /// * `sink` - The sender the events are sent to
+    ///
+    /// # Returns
+    ///
+    /// A new `EventSecretClient`
+    pub fn new(inner: C, sink: UnboundedSender<SecretEvent>) -> EventSecretClient<C> {
+        EventSecretClient {
+            inner,
+            sink: EventSink::new(sink),
+        }
+    }
+}
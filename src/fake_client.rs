@@ -5,34 +5,180 @@
 //! Fake secret client implementation for testing and development.
 //!
 //! This module provides a `FakeSecretClient` that implements the `SecretClient` trait
-//! but returns empty strings instead of actual secrets. Useful for testing and
-//! development environments.
+//! and serves secrets from an in-memory map seeded by the caller, or empty strings
+//! when no map was provided. Useful for testing and development environments.
 
-use crate::{SecretClient, errors::SecretsManagerError};
+use crate::{
+    BinarySecretClient, HealthCheckableSecretClient, SecretClient, SecretValue,
+    errors::SecretsManagerError,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+};
+use tracing::error;
 
 /// A fake implementation of the `SecretClient` trait for testing.
 ///
-/// This client always returns an empty string for any secret key, making it
-/// useful for tests and development environments where actual secrets
-/// are not required or available.
-#[derive(Default)]
-pub struct FakeSecretClient;
+/// When created with [`FakeSecretClient::new`], this client returns an empty
+/// string for any secret key. When created with [`FakeSecretClient::with_secrets`]
+/// or [`FakeSecretClient::builder`], it serves only the seeded values and reports
+/// any other key as not found. Errors seeded through the builder take precedence
+/// over values, allowing error-handling code paths to be tested without `mockall`.
+///
+/// Cloning copies the seeded secrets and errors at the time of the clone; the
+/// clone is not a live handle to the original client. Clones of a recording
+/// client share its recorded calls, though.
+///
+/// Its `Debug` output lists the seeded keys but never their values:
+///
+/// ```rust
+/// use secrets_manager::FakeSecretClient;
+/// use std::collections::HashMap;
+///
+/// let client = FakeSecretClient::with_secrets(HashMap::from([(
+///     "api-key".to_string(),
+///     "value".to_string(),
+/// )]));
+///
+/// assert_eq!(
+///     format!("{client:?}"),
+///     r#"FakeSecretClient { keys: Some(["api-key"]), error_keys: [] }"#
+/// );
+/// ```
+#[derive(Default, Clone)]
+pub struct FakeSecretClient {
+    /// The seeded secrets, if any
+    secrets: Option<HashMap<String, String>>,
+    /// The seeded errors returned for specific keys
+    errors: HashMap<String, SecretsManagerError>,
+    /// The keys passed to `get_by_key`, if recording
+    calls: Option<Arc<Mutex<Vec<String>>>>,
+}
+
+impl fmt::Debug for FakeSecretClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys = self
+            .secrets
+            .as_ref()
+            .map(|secrets| secrets.keys().collect::<Vec<_>>());
+
+        f.debug_struct("FakeSecretClient")
+            .field("keys", &keys)
+            .field("error_keys", &self.errors.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Shows the number of seeded secrets, e.g. in startup logs.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::FakeSecretClient;
+///
+/// let client = FakeSecretClient::builder().set("api-key", "value").build();
+/// assert_eq!(client.to_string(), "FakeSecretClient { key_count: 1 }");
+/// ```
+impl fmt::Display for FakeSecretClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key_count = self.secrets.as_ref().map_or(0, HashMap::len);
+
+        write!(f, "FakeSecretClient {{ key_count: {key_count} }}")
+    }
+}
 
 impl SecretClient for FakeSecretClient {
-    /// Always returns an empty string for any key.
+    /// Retrieves a secret from the seeded secrets by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The seeded value, or an empty value if no secrets were seeded
+    /// * `Err(SecretsManagerError)` - The error seeded for the key, if any
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If secrets were seeded but the key is absent
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        if let Some(calls) = &self.calls {
+            calls
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(key.to_string());
+        }
+
+        if let Some(err) = self.errors.get(key) {
+            return Err(err.clone());
+        }
+
+        let Some(secrets) = &self.secrets else {
+            return Ok(SecretValue::default());
+        };
+
+        let Some(secret) = secrets.get(key) else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(SecretValue::from(secret.clone()))
+    }
+
+    /// Checks whether the seeded secrets hold the key.
     ///
     /// # Arguments
     ///
-    /// * `_key` - The key is ignored in this implementation
+    /// * `key` - The key identifying the secret
     ///
     /// # Returns
     ///
-    /// Always `Ok(String::new())`
-    fn get_by_key(&self, _key: &str) -> Result<String, SecretsManagerError> {
-        Ok(String::new())
+    /// `true` if the key was seeded or no secrets were seeded, `false` otherwise
+    /// or if an error was seeded for the key
+    fn contains_key(&self, key: &str) -> bool {
+        !self.errors.contains_key(key)
+            && self
+                .secrets
+                .as_ref()
+                .is_none_or(|secrets| secrets.contains_key(key))
+    }
+
+    /// Retrieves all seeded secrets.
+    ///
+    /// Keys an error was seeded for are left out.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - The seeded secrets, or an empty map if none were seeded
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        Ok(self
+            .secrets
+            .iter()
+            .flatten()
+            .filter(|(key, _)| !self.errors.contains_key(*key))
+            .map(|(key, value)| (key.clone(), SecretValue::from(value.clone())))
+            .collect())
+    }
+
+    /// Lists the keys of the seeded secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The seeded keys, or an empty list if no secrets were seeded
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self
+            .secrets
+            .as_ref()
+            .map(|secrets| secrets.keys().cloned().collect())
+            .unwrap_or_default())
     }
 }
 
+/// The fake client has no backend, so it is always available.
+impl HealthCheckableSecretClient for FakeSecretClient {}
+
 impl FakeSecretClient {
     /// Creates a new `FakeSecretClient` instance.
     ///
@@ -40,6 +186,259 @@ impl FakeSecretClient {
     ///
     /// A new `FakeSecretClient`
     pub fn new() -> FakeSecretClient {
-        FakeSecretClient {}
+        FakeSecretClient::default()
+    }
+
+    /// Creates a new `FakeSecretClient` recording the keys of its `get_by_key` calls.
+    ///
+    /// Like [`FakeSecretClient::new`], the client returns an empty string for any key.
+    ///
+    /// # Returns
+    ///
+    /// A new recording `FakeSecretClient`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient};
+    ///
+    /// let client = FakeSecretClient::recording();
+    /// let _ = client.get_by_key("db-user");
+    /// let _ = client.get_by_key("db-pass");
+    ///
+    /// assert_eq!(client.recorded_calls(), vec!["db-user", "db-pass"]);
+    /// client.assert_called_with(&["db-user", "db-pass"]);
+    /// ```
+    pub fn recording() -> FakeSecretClient {
+        FakeSecretClient {
+            calls: Some(Arc::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the keys passed to `get_by_key`, in call order.
+    ///
+    /// # Returns
+    ///
+    /// The recorded keys, or an empty list if the client is not recording
+    pub fn recorded_calls(&self) -> Vec<String> {
+        self.calls
+            .as_ref()
+            .map(|calls| calls.lock().unwrap_or_else(PoisonError::into_inner).clone())
+            .unwrap_or_default()
+    }
+
+    /// Asserts that `get_by_key` was called with exactly the given keys, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The expected keys, in call order
+    ///
+    /// # Panics
+    ///
+    /// If the recorded keys differ from the expected ones, or the client is not recording
+    pub fn assert_called_with(&self, keys: &[&str]) {
+        assert!(
+            self.calls.is_some(),
+            "FakeSecretClient is not recording, create it with FakeSecretClient::recording"
+        );
+        assert_eq!(
+            self.recorded_calls(),
+            keys,
+            "FakeSecretClient was not called with the expected keys"
+        );
+    }
+
+    /// Creates a new builder for seeding secrets and errors.
+    ///
+    /// # Returns
+    ///
+    /// A new `FakeSecretClientBuilder`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient, errors::SecretsManagerError};
+    ///
+    /// let client = FakeSecretClient::builder()
+    ///     .set("api-key", "value")
+    ///     .set_error("db-pass", SecretsManagerError::request_failure("unreachable"))
+    ///     .build();
+    ///
+    /// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+    /// assert!(matches!(
+    ///     client.get_by_key("db-pass"),
+    ///     Err(SecretsManagerError::RequestFailure { .. })
+    /// ));
+    /// ```
+    pub fn builder() -> FakeSecretClientBuilder {
+        FakeSecretClientBuilder::default()
+    }
+
+    /// Creates a new `FakeSecretClient` that returns the given error for a key.
+    ///
+    /// Any other key is reported as not found.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the error is returned for
+    /// * `error` - The error returned when the key is retrieved
+    ///
+    /// # Returns
+    ///
+    /// A new `FakeSecretClient`
+    pub fn with_error(key: &str, error: SecretsManagerError) -> FakeSecretClient {
+        FakeSecretClient::builder().set_error(key, error).build()
+    }
+
+    /// Creates a new `FakeSecretClient` seeded with the given secrets.
+    ///
+    /// # Arguments
+    ///
+    /// * `secrets` - The key-value pairs the client should serve
+    ///
+    /// # Returns
+    ///
+    /// A new `FakeSecretClient` that returns `SecretsManagerError::SecretNotFound`
+    /// for any key not present in `secrets`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FakeSecretClient, SecretClient, errors::SecretsManagerError};
+    /// use std::collections::HashMap;
+    ///
+    /// let client = FakeSecretClient::with_secrets(HashMap::from([(
+    ///     "api-key".to_string(),
+    ///     "value".to_string(),
+    /// )]));
+    ///
+    /// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+    /// assert_eq!(
+    ///     client.get_by_key("missing"),
+    ///     Err(SecretsManagerError::SecretNotFound { key: "missing".to_string() })
+    /// );
+    /// ```
+    pub fn with_secrets(secrets: HashMap<String, String>) -> FakeSecretClient {
+        FakeSecretClient {
+            secrets: Some(secrets),
+            ..Default::default()
+        }
+    }
+}
+
+/// Builder for `FakeSecretClient` instances seeded with values and errors.
+#[derive(Default)]
+pub struct FakeSecretClientBuilder {
+    /// The seeded secrets
+    secrets: HashMap<String, String>,
+    /// The seeded errors returned for specific keys
+    errors: HashMap<String, SecretsManagerError>,
+    /// Whether the built client records its calls
+    recording: bool,
+}
+
+impl FakeSecretClientBuilder {
+    /// Seeds a secret value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the secret
+    /// * `value` - The value returned for the key
+    ///
+    /// # Returns
+    ///
+    /// The builder with the secret seeded
+    pub fn set(mut self, key: &str, value: &str) -> FakeSecretClientBuilder {
+        self.secrets.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Seeds an error returned for a key.
+    ///
+    /// Errors take precedence over values seeded for the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the error is returned for
+    /// * `error` - The error returned when the key is retrieved
+    ///
+    /// # Returns
+    ///
+    /// The builder with the error seeded
+    pub fn set_error(mut self, key: &str, error: SecretsManagerError) -> FakeSecretClientBuilder {
+        self.errors.insert(key.to_string(), error);
+        self
+    }
+
+    /// Records the keys of the `get_by_key` calls of the built client.
+    ///
+    /// # Returns
+    ///
+    /// The builder with call recording enabled
+    pub fn recording(mut self) -> FakeSecretClientBuilder {
+        self.recording = true;
+        self
+    }
+
+    /// Builds and returns a `FakeSecretClient` instance.
+    ///
+    /// # Returns
+    ///
+    /// A new `FakeSecretClient` serving the seeded secrets and errors
+    pub fn build(self) -> FakeSecretClient {
+        FakeSecretClient {
+            secrets: Some(self.secrets),
+            errors: self.errors,
+            calls: self.recording.then(Arc::default),
+        }
+    }
+}
+
+/// A fake implementation of the `BinarySecretClient` trait for testing.
+///
+/// This client serves binary secrets from an in-memory map seeded by the caller
+/// and reports any other key as not found.
+#[derive(Default)]
+pub struct FakeBinarySecretClient {
+    /// The seeded binary secrets
+    secrets: HashMap<String, Vec<u8>>,
+}
+
+impl BinarySecretClient for FakeBinarySecretClient {
+    /// Retrieves a binary secret from the seeded secrets by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The seeded bytes
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the key is absent
+    fn get_binary_by_key(&self, key: &str) -> Result<Vec<u8>, SecretsManagerError> {
+        let Some(secret) = self.secrets.get(key) else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(secret.clone())
+    }
+}
+
+impl FakeBinarySecretClient {
+    /// Creates a new `FakeBinarySecretClient` seeded with the given secrets.
+    ///
+    /// # Arguments
+    ///
+    /// * `secrets` - The key-bytes pairs the client should serve
+    ///
+    /// # Returns
+    ///
+    /// A new `FakeBinarySecretClient`
+    pub fn with_secrets(secrets: HashMap<String, Vec<u8>>) -> FakeBinarySecretClient {
+        FakeBinarySecretClient { secrets }
     }
 }
@@ -0,0 +1,234 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Chained secret client implementation.
+//!
+//! This module provides the `FallbackSecretClient` which tries a list of
+//! `SecretClient` implementations in order, e.g. Vault in production and
+//! environment variables during local development.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use tracing::{debug, error};
+
+/// Client that tries several secret clients in sequence.
+///
+/// A client is only skipped when it reports the secret as not found; any other
+/// error is treated as a hard failure and returned immediately.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{
+///     FallbackSecretClient, FakeSecretClient, SecretClient, SecretValue,
+///     errors::SecretsManagerError,
+/// };
+/// use std::collections::HashMap;
+///
+/// struct BrokenClient;
+///
+/// impl SecretClient for BrokenClient {
+///     fn get_by_key(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+///         Err(SecretsManagerError::internal("broken"))
+///     }
+/// }
+///
+/// let seeded = || {
+///     FakeSecretClient::with_secrets(HashMap::from([(
+///         "api-key".to_string(),
+///         "value".to_string(),
+///     )]))
+/// };
+///
+/// // The first client does not know the key, so the second one is tried
+/// let client = FallbackSecretClient::new(vec![
+///     Box::new(FakeSecretClient::with_secrets(HashMap::new())),
+///     Box::new(seeded()),
+/// ]);
+/// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+///
+/// // A hard failure short-circuits the chain
+/// let client = FallbackSecretClient::new(vec![Box::new(BrokenClient), Box::new(seeded())]);
+/// assert_eq!(
+///     client.get_by_key("api-key"),
+///     Err(SecretsManagerError::internal("broken"))
+/// );
+/// ```
+pub struct FallbackSecretClient {
+    /// The clients to try, in order
+    clients: Vec<Box<dyn SecretClient>>,
+}
+
+#[async_trait]
+impl SecretClient for FallbackSecretClient {
+    /// Retrieves a secret from the first client that has it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The value returned by the first client that found the secret
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client found the secret
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.first_found(key, |client| client.get_by_key(key))
+    }
+
+    /// Retrieves a secret asynchronously from the first client that has it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The value returned by the first client that found the secret
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client found the secret
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let mut last_error = SecretsManagerError::SecretNotFound {
+            key: key.to_string(),
+        };
+
+        for (index, client) in self.clients.iter().enumerate() {
+            match client.get_by_key_async(key).await {
+                Err(err @ SecretsManagerError::SecretNotFound { .. }) => {
+                    debug!(
+                        key = key,
+                        index = index,
+                        "secret not found, trying next client"
+                    );
+                    last_error = err;
+                }
+                result => return fallback_result(key, result),
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Retrieves a secret as a JSON object from the first client that has it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client found the secret
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.first_found(key, |client| client.get_json_object_by_key(key))
+    }
+
+    /// Retrieves a secret as a JSON array from the first client that has it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client found the secret
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.first_found(key, |client| client.get_json_array_by_key(key))
+    }
+
+    /// Lists the keys available from any of the clients.
+    ///
+    /// Clients that cannot list their keys are skipped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The deduplicated keys of all clients
+    /// * `Err(SecretsManagerError)` - The first error that is not `NotSupported`
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        let mut keys = Vec::new();
+
+        for client in &self.clients {
+            match client.list_keys() {
+                Ok(client_keys) => keys.extend(client_keys),
+                Err(SecretsManagerError::NotSupported) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        keys.sort();
+        keys.dedup();
+
+        Ok(keys)
+    }
+}
+
+impl FallbackSecretClient {
+    /// Creates a new `FallbackSecretClient` from the given clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `clients` - The clients to try, in order
+    ///
+    /// # Returns
+    ///
+    /// A new `FallbackSecretClient`
+    pub fn new(clients: Vec<Box<dyn SecretClient>>) -> FallbackSecretClient {
+        FallbackSecretClient { clients }
+    }
+
+    /// Returns the result of the first client that has the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    /// * `get` - The retrieval of the secret from a client
+    ///
+    /// # Returns
+    ///
+    /// The result of the first client not reporting `SecretNotFound`, or the
+    /// last `SecretNotFound` if no client found the secret
+    fn first_found<T>(
+        &self,
+        key: &str,
+        get: impl Fn(&dyn SecretClient) -> Result<T, SecretsManagerError>,
+    ) -> Result<T, SecretsManagerError> {
+        let mut last_error = SecretsManagerError::SecretNotFound {
+            key: key.to_string(),
+        };
+
+        for (index, client) in self.clients.iter().enumerate() {
+            match get(client.as_ref()) {
+                Err(err @ SecretsManagerError::SecretNotFound { .. }) => {
+                    debug!(
+                        key = key,
+                        index = index,
+                        "secret not found, trying next client"
+                    );
+                    last_error = err;
+                }
+                result => return fallback_result(key, result),
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+/// Logs the hard failure of a client, if the result of the chain is one.
+fn fallback_result<T>(
+    key: &str,
+    result: Result<T, SecretsManagerError>,
+) -> Result<T, SecretsManagerError> {
+    if let Err(err) = &result {
+        error!(error = err.to_string(), key = key, "secret client failed");
+    }
+
+    result
+}
@@ -0,0 +1,53 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! `figment` integration.
+//!
+//! This module implements `figment::Provider` for `AWSSecretClient`, so that its
+//! secrets can be merged into a hierarchical `Figment` configuration.
+
+use crate::AWSSecretClient;
+use figment::{
+    Error, Metadata, Profile, Provider,
+    error::Kind,
+    value::{Dict, Map, Value},
+};
+
+/// Provides the cached secret JSON object as the data of the default profile.
+///
+/// The object is provided as is, including nested objects and non-string
+/// values. Merging other providers afterwards gives them a higher priority.
+///
+/// # Examples
+///
+/// ```rust
+/// use figment::Figment;
+/// use secrets_manager::AWSSecretClient;
+/// use serde_json::json;
+///
+/// let client = AWSSecretClient::try_from(json!({
+///     "db": { "password": "secret", "port": 5432 }
+/// }))
+/// .unwrap();
+///
+/// let figment = Figment::new().merge(client);
+///
+/// assert_eq!(figment.extract_inner::<String>("db.password").unwrap(), "secret");
+/// assert_eq!(figment.extract_inner::<u16>("db.port").unwrap(), 5432);
+/// ```
+impl Provider for AWSSecretClient {
+    fn metadata(&self) -> Metadata {
+        Metadata::named("AWS Secrets Manager").source(self.request.secret_id.clone())
+    }
+
+    fn data(&self) -> Result<Map<Profile, Dict>, Error> {
+        let secrets = self.loaded().map_err(|err| Error::from(err.to_string()))?;
+
+        let value = Value::serialize(secrets)?;
+        let error = Kind::InvalidType(value.to_actual(), "map".into());
+        let dict = value.into_dict().ok_or(error)?;
+
+        Ok(Profile::Default.collect(dict))
+    }
+}
@@ -0,0 +1,492 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! File-based secret client implementation.
+//!
+//! This module provides the `FileSecretClient` which implements the `SecretClient` trait
+//! for secrets stored in a plain JSON, TOML or `.env` file, as commonly mounted into
+//! containers in integration test or Docker Compose environments, or in a JSON file
+//! encrypted with SOPS behind the `sops` feature.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+#[cfg(feature = "sops")]
+use std::{io::ErrorKind, process::Command};
+use tracing::error;
+
+/// The format of a secrets file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileFormat {
+    /// A JSON object of string values
+    Json,
+    /// A TOML table of string values
+    Toml,
+    /// `KEY=VALUE` lines of a `.env` file
+    Dotenv,
+    /// A JSON object of string values encrypted with SOPS
+    #[cfg(feature = "sops")]
+    SopsJson,
+}
+
+/// Client for accessing secrets stored in a local file.
+///
+/// The file is loaded synchronously at construction and its secrets are kept
+/// in memory. Call [`FileSecretClient::reload`] to pick up changes made to the file.
+pub struct FileSecretClient {
+    /// The path of the secrets file
+    path: PathBuf,
+    /// The format of the secrets file
+    format: FileFormat,
+    /// The cached secrets
+    secrets: HashMap<String, String>,
+}
+
+impl SecretClient for FileSecretClient {
+    /// Retrieves a secret from the cached secrets by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let Some(secret) = self.secrets.get(key) else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(SecretValue::from(secret.clone()))
+    }
+
+    /// Lists the keys of the cached secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self.secrets.keys().cloned().collect())
+    }
+}
+
+impl FileSecretClient {
+    /// Creates a new `FileSecretClient` from a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of a JSON file holding an object of string values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FileSecretClient)` - If the file was successfully read and parsed
+    /// * `Err(SecretsManagerError)` - If the file couldn't be read or parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FileSecretClient, SecretClient};
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/secrets.json");
+    /// let client = FileSecretClient::from_json_file(&path).unwrap();
+    ///
+    /// assert_eq!(&*client.get_by_key("api-key").unwrap(), "top-level-key");
+    /// assert_eq!(&*client.get_by_key("database.password").unwrap(), "x");
+    /// ```
+    pub fn from_json_file(path: &Path) -> Result<FileSecretClient, SecretsManagerError> {
+        FileSecretClient::load(path, FileFormat::Json)
+    }
+
+    /// Creates a new `FileSecretClient` from a JSON file encrypted with SOPS.
+    ///
+    /// The file is decrypted by running `sops --decrypt --output-type json`, so
+    /// the `sops` binary must be on the `PATH` along with access to the keys the
+    /// file was encrypted with, e.g. through `SOPS_AGE_KEY_FILE` or AWS KMS
+    /// credentials. The decrypted secrets are only kept in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of a SOPS-encrypted file holding an object of string values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FileSecretClient)` - If the file was successfully decrypted and parsed
+    /// * `Err(SecretsManagerError::InternalError)` - If `sops` is not installed or
+    ///   couldn't decrypt the file
+    /// * `Err(SecretsManagerError::DeserializationError)` - If the decrypted file couldn't be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use secrets_manager::{FileSecretClient, SecretClient};
+    /// use std::path::Path;
+    ///
+    /// let client = FileSecretClient::from_sops_json(Path::new("secrets.enc.json")).unwrap();
+    /// let password = client.get_by_key("database.password").unwrap();
+    /// ```
+    #[cfg(feature = "sops")]
+    pub fn from_sops_json(path: &Path) -> Result<FileSecretClient, SecretsManagerError> {
+        FileSecretClient::load(path, FileFormat::SopsJson)
+    }
+
+    /// Creates a new `FileSecretClient` from a TOML file.
+    ///
+    /// Nested tables are flattened with dot notation, so `password = "x"` in a
+    /// `[database]` table is stored under the `database.password` key. Integers,
+    /// floats, booleans and datetimes are stored in their TOML representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of a TOML file holding a table of scalar values or nested tables
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FileSecretClient)` - If the file was successfully read and parsed
+    /// * `Err(SecretsManagerError)` - If the file couldn't be read or parsed, holds an
+    ///   array, or two values flattened to the same key
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FileSecretClient, SecretClient};
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/secrets.toml");
+    /// let client = FileSecretClient::from_toml_file(&path).unwrap();
+    ///
+    /// assert_eq!(&*client.get_by_key("api-key").unwrap(), "top-level-key");
+    /// assert_eq!(&*client.get_by_key("database.password").unwrap(), "x");
+    /// assert_eq!(&*client.get_by_key("database.port").unwrap(), "5432");
+    /// assert_eq!(&*client.get_by_key("database.replica.password").unwrap(), "y");
+    /// assert!(client.get_by_key("database").is_err());
+    /// ```
+    pub fn from_toml_file(path: &Path) -> Result<FileSecretClient, SecretsManagerError> {
+        FileSecretClient::load(path, FileFormat::Toml)
+    }
+
+    /// Creates a new `FileSecretClient` from a `.env` file.
+    ///
+    /// Each non-empty line holds a `KEY=VALUE` pair, optionally preceded by
+    /// `export`. Lines starting with `#` are comments, as is the rest of an
+    /// unquoted value after ` #`. Values may be wrapped in single quotes, taken
+    /// literally, or double quotes, in which `\n`, `\"` and `\\` are unescaped.
+    /// A quoted value may be followed by a `#` comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the `.env` file
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FileSecretClient)` - If the file was successfully read and parsed
+    /// * `Err(SecretsManagerError)` - If the file couldn't be read or holds a malformed line
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::{FileSecretClient, SecretClient};
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/secrets.env");
+    /// let client = FileSecretClient::from_dotenv_file(&path).unwrap();
+    ///
+    /// assert_eq!(&*client.get_by_key("DATABASE_USER").unwrap(), "admin");
+    /// assert_eq!(&*client.get_by_key("DATABASE_PASSWORD").unwrap(), "p@ss word");
+    /// assert_eq!(&*client.get_by_key("API_KEY").unwrap(), "literal $value");
+    /// assert_eq!(&*client.get_by_key("GREETING").unwrap(), "line one\nline two");
+    /// assert_eq!(&*client.get_by_key("TIMEOUT").unwrap(), "30");
+    /// assert_eq!(&*client.get_by_key("REGION").unwrap(), "eu-west-1");
+    /// assert_eq!(&*client.get_by_key("EMPTY").unwrap(), "");
+    /// ```
+    pub fn from_dotenv_file(path: &Path) -> Result<FileSecretClient, SecretsManagerError> {
+        FileSecretClient::load(path, FileFormat::Dotenv)
+    }
+
+    /// Re-reads the secrets file and replaces the cached secrets.
+    ///
+    /// The cached secrets are left untouched if the file couldn't be read or parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the secrets were successfully reloaded
+    /// * `Err(SecretsManagerError)` - If the file couldn't be read or parsed
+    pub fn reload(&mut self) -> Result<(), SecretsManagerError> {
+        self.secrets = read_secrets(&self.path, self.format)?;
+        Ok(())
+    }
+
+    /// Reads the secrets file and creates a client holding its secrets.
+    fn load(path: &Path, format: FileFormat) -> Result<FileSecretClient, SecretsManagerError> {
+        let secrets = read_secrets(path, format)?;
+
+        Ok(FileSecretClient {
+            path: path.to_path_buf(),
+            format,
+            secrets,
+        })
+    }
+}
+
+/// Reads and parses a secrets file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the secrets file
+/// * `format` - The format of the secrets file
+///
+/// # Returns
+///
+/// * `Ok(HashMap<String, String>)` - The parsed secrets
+/// * `Err(SecretsManagerError::InternalError)` - If the file couldn't be read
+/// * `Err(SecretsManagerError::DeserializationError)` - If the file couldn't be parsed
+fn read_secrets(
+    path: &Path,
+    format: FileFormat,
+) -> Result<HashMap<String, String>, SecretsManagerError> {
+    let content = match format {
+        #[cfg(feature = "sops")]
+        FileFormat::SopsJson => decrypt_sops(path)?,
+        _ => match fs::read_to_string(path) {
+            Err(err) => {
+                error!(
+                    error = err.to_string(),
+                    path = path.display().to_string(),
+                    "failure to read secrets file"
+                );
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(c) => Ok(c),
+        }?,
+    };
+
+    let secrets = match format {
+        FileFormat::Json => serde_json::from_str(&content).map_err(|err| err.to_string()),
+        #[cfg(feature = "sops")]
+        FileFormat::SopsJson => serde_json::from_str(&content).map_err(|err| err.to_string()),
+        FileFormat::Toml => toml::from_str(&content)
+            .map_err(|err| err.to_string())
+            .and_then(|table| {
+                let mut secrets = HashMap::new();
+                flatten_toml("", table, &mut secrets)?;
+                Ok(secrets)
+            }),
+        FileFormat::Dotenv => parse_dotenv(&content),
+    };
+
+    secrets.map_err(|err| {
+        error!(error = err, "error mapping secrets file");
+        SecretsManagerError::DeserializationError(err)
+    })
+}
+
+/// Decrypts a SOPS-encrypted file to JSON with the `sops` binary.
+///
+/// # Arguments
+///
+/// * `path` - The path of the encrypted file
+///
+/// # Returns
+///
+/// * `Ok(String)` - The decrypted JSON
+/// * `Err(SecretsManagerError::InternalError)` - If `sops` couldn't be run or failed
+#[cfg(feature = "sops")]
+fn decrypt_sops(path: &Path) -> Result<String, SecretsManagerError> {
+    let output = match Command::new("sops")
+        .args(["--decrypt", "--output-type", "json"])
+        .arg(path)
+        .output()
+    {
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            error!("sops binary was not found");
+            Err(SecretsManagerError::internal(
+                "sops binary was not found in PATH, install it to decrypt SOPS files",
+            ))
+        }
+        Err(err) => {
+            error!(error = err.to_string(), "failure to run sops");
+            Err(SecretsManagerError::internal(err))
+        }
+        Ok(o) => Ok(o),
+    }?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        error!(
+            error = stderr.trim(),
+            path = path.display().to_string(),
+            "failure to decrypt secrets file"
+        );
+        return Err(SecretsManagerError::internal(format!(
+            "sops failed to decrypt {}: {}",
+            path.display(),
+            stderr.trim()
+        )));
+    }
+
+    String::from_utf8(output.stdout).map_err(|err| {
+        error!(
+            error = err.to_string(),
+            "decrypted secrets file is not utf-8"
+        );
+        SecretsManagerError::internal(err)
+    })
+}
+
+/// Flattens a TOML table into dot-separated keys.
+///
+/// # Arguments
+///
+/// * `prefix` - The dot-separated path of the table, empty for the root table
+/// * `table` - The table to flatten
+/// * `secrets` - The map the flattened values are inserted into
+///
+/// # Returns
+///
+/// * `Ok(())` - If every value was flattened
+/// * `Err(String)` - If the table holds an array, which has no single string value,
+///   or two values flattened to the same key, e.g. `"database.password"` and
+///   `password` of a `[database]` table
+fn flatten_toml(
+    prefix: &str,
+    table: toml::Table,
+    secrets: &mut HashMap<String, String>,
+) -> Result<(), String> {
+    for (key, value) in table {
+        let key = match prefix.is_empty() {
+            true => key,
+            false => format!("{prefix}.{key}"),
+        };
+
+        let value = match value {
+            toml::Value::Table(table) => {
+                flatten_toml(&key, table, secrets)?;
+                continue;
+            }
+            toml::Value::Array(_) => return Err(format!("unsupported array value for {key}")),
+            toml::Value::String(value) => value,
+            value => value.to_string(),
+        };
+
+        if secrets.contains_key(&key) {
+            return Err(format!("duplicate value for {key}"));
+        }
+
+        secrets.insert(key, value);
+    }
+
+    Ok(())
+}
+
+/// Parses the `KEY=VALUE` lines of a `.env` file.
+///
+/// # Arguments
+///
+/// * `content` - The content of the `.env` file
+///
+/// # Returns
+///
+/// * `Ok(HashMap<String, String>)` - The parsed secrets
+/// * `Err(String)` - If a line has no `=` or an unterminated quoted value
+fn parse_dotenv(content: &str) -> Result<HashMap<String, String>, String> {
+    let mut secrets = HashMap::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("missing '=' on line {}", index + 1));
+        };
+
+        let value = value.trim();
+        let value = if let Some(quoted) = value.strip_prefix('"') {
+            let Some((quoted, rest)) = split_quoted(quoted, '"') else {
+                return Err(format!("unterminated double quote on line {}", index + 1));
+            };
+            check_after_quote(rest, index + 1)?;
+            unescape(quoted)
+        } else if let Some(quoted) = value.strip_prefix('\'') {
+            let Some((quoted, rest)) = split_quoted(quoted, '\'') else {
+                return Err(format!("unterminated single quote on line {}", index + 1));
+            };
+            check_after_quote(rest, index + 1)?;
+            quoted.to_string()
+        } else {
+            let value = value.split_once(" #").map_or(value, |(value, _)| value);
+            value.trim_end().to_string()
+        };
+
+        secrets.insert(key.trim().to_string(), value);
+    }
+
+    Ok(secrets)
+}
+
+/// Splits a quoted `.env` value, without its opening quote, at its closing quote.
+///
+/// Escaped double quotes do not close a double-quoted value, while single-quoted
+/// values are taken literally and end at the next single quote.
+///
+/// # Returns
+///
+/// The quoted text and what follows the closing quote, or `None` if the value is unterminated
+fn split_quoted(value: &str, quote: char) -> Option<(&str, &str)> {
+    let mut escaped = false;
+
+    for (index, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quote == '"' => escaped = true,
+            c if c == quote => return Some((&value[..index], &value[index + 1..])),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Checks that only whitespace or a `#` comment follows the closing quote of a `.env` value.
+fn check_after_quote(rest: &str, line: usize) -> Result<(), String> {
+    let rest = rest.trim_start();
+
+    match rest.is_empty() || rest.starts_with('#') {
+        true => Ok(()),
+        false => Err(format!(
+            "unexpected {rest:?} after closing quote on line {line}"
+        )),
+    }
+}
+
+/// Unescapes `\n`, `\"` and `\\` in a double-quoted `.env` value.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Google Cloud Secret Manager client implementation.
+//!
+//! This module provides the `GcpSecretClient` which implements the `SecretClient` trait
+//! for retrieving secrets from Google Cloud Secret Manager.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError, lookup::lookup};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use serde_json::Value;
+
+/// The name of the backend, as logged when a secret is not found.
+const BACKEND: &str = "GCP Secret Manager";
+
+/// Client for accessing secrets from Google Cloud Secret Manager.
+///
+/// This client stores the parsed JSON payload of a secret version in memory
+/// after it has been retrieved from GCP and provides methods to access it by key.
+#[derive(Default)]
+pub struct GcpSecretClient {
+    /// The cached secrets as a JSON Value
+    pub(crate) secrets: Value,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl SecretClient for GcpSecretClient {
+    /// Retrieves a secret from the cached secrets by its key.
+    ///
+    /// Dot-separated keys resolve nested objects, as with the AWS client.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        lookup(&self.secrets, key, BACKEND)
+    }
+
+    /// Lists the top-level keys of the cached secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        let Value::Object(secrets) = &self.secrets else {
+            return Ok(Vec::new());
+        };
+
+        Ok(secrets.keys().cloned().collect())
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Google Cloud Secret Manager client builder module.
+//!
+//! This module provides the `GcpSecretClientBuilder` for constructing instances of
+//! `GcpSecretClient` with appropriate configuration.
+
+use crate::{GcpSecretClient, errors::SecretsManagerError};
+use google_cloud_auth::credentials::service_account;
+use google_cloud_gax::error::rpc::Code;
+use google_cloud_secretmanager_v1::{Error, client::SecretManagerService};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use serde_json::Value;
+use tracing::error;
+
+/// The version alias resolving to the most recent secret version.
+const LATEST_VERSION: &str = "latest";
+
+/// Builder for Google Cloud Secret Manager client instances.
+///
+/// This builder facilitates the creation of `GcpSecretClient` instances by
+/// handling the authentication and the retrieval of a secret version.
+/// Application Default Credentials are used unless a service account key is given.
+#[derive(Default)]
+pub struct GcpSecretClientBuilder {
+    /// The GCP project the secret belongs to
+    project_id: String,
+    /// The name of the secret
+    secret_name: String,
+    /// The version of the secret to retrieve, `latest` if not set
+    version: Option<String>,
+    /// The path of a service account JSON key file used to authenticate
+    service_account_path: Option<String>,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl GcpSecretClientBuilder {
+    /// Creates a new builder for the given GCP secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The GCP project the secret belongs to
+    /// * `secret_name` - The name of the secret
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `GcpSecretClientBuilder`
+    pub fn new(project_id: &str, secret_name: &str) -> GcpSecretClientBuilder {
+        GcpSecretClientBuilder {
+            project_id: project_id.to_string(),
+            secret_name: secret_name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the version of the secret to retrieve.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - `latest` or an integer version, e.g. `"3"`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the version configured
+    pub fn version(mut self, version: &str) -> GcpSecretClientBuilder {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Authenticates with a service account key instead of Application Default Credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the service account JSON key file
+    ///
+    /// # Returns
+    ///
+    /// The builder with the service account configured
+    pub fn service_account_path(mut self, path: &str) -> GcpSecretClientBuilder {
+        self.service_account_path = Some(path.to_string());
+        self
+    }
+
+    /// Builds and returns a `GcpSecretClient` instance.
+    ///
+    /// This asynchronous method:
+    /// 1. Configures the Secret Manager client
+    /// 2. Accesses the secret version
+    /// 3. Parses the JSON payload
+    /// 4. Creates a `GcpSecretClient` with the parsed secrets
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GcpSecretClient)` - If the secret was successfully retrieved
+    /// * `Err(SecretsManagerError)` - If any step failed
+    pub async fn build(&self) -> Result<GcpSecretClient, SecretsManagerError> {
+        let mut builder = SecretManagerService::builder();
+
+        if let Some(path) = &self.service_account_path {
+            let key = match std::fs::read_to_string(path)
+                .map_err(|err| err.to_string())
+                .and_then(|s| serde_json::from_str::<Value>(&s).map_err(|err| err.to_string()))
+            {
+                Err(err) => {
+                    error!(error = err, "failure to read service account key");
+                    Err(SecretsManagerError::internal(err))
+                }
+                Ok(k) => Ok(k),
+            }?;
+
+            let credentials = match service_account::Builder::new(key).build() {
+                Err(err) => {
+                    error!(error = err.to_string(), "invalid service account key");
+                    Err(SecretsManagerError::internal(err))
+                }
+                Ok(c) => Ok(c),
+            }?;
+
+            builder = builder.with_credentials(credentials);
+        }
+
+        let client = match builder.build().await {
+            Err(err) => {
+                error!(error = err.to_string(), "failure to create gcp client");
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(c) => Ok(c),
+        }?;
+
+        let name = format!(
+            "projects/{}/secrets/{}/versions/{}",
+            self.project_id,
+            self.secret_name,
+            self.version.as_deref().unwrap_or(LATEST_VERSION)
+        );
+
+        let response = match client.access_secret_version().set_name(&name).send().await {
+            Err(err) => {
+                error!(
+                    error = err.to_string(),
+                    "failure send request to secret manager"
+                );
+                Err(map_error(&err, &name))
+            }
+            Ok(r) => Ok(r),
+        }?;
+
+        let data = response.payload.map(|p| p.data).unwrap_or_default();
+
+        match serde_json::from_slice(&data) {
+            Err(err) => {
+                error!(error = err.to_string(), "error mapping secrets");
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(secrets) => Ok(GcpSecretClient { secrets }),
+        }
+    }
+}
+
+/// Maps a GCP error to the matching `SecretsManagerError`.
+///
+/// A missing secret version is reported with the resource name that was requested,
+/// so that the error tells which project, secret and version were not found.
+fn map_error(err: &Error, name: &str) -> SecretsManagerError {
+    if err.is_timeout() {
+        return SecretsManagerError::Timeout;
+    }
+
+    match err.status().map(|status| status.code) {
+        Some(Code::NotFound) => SecretsManagerError::SecretNotFound {
+            key: name.to_string(),
+        },
+        Some(Code::ResourceExhausted) => SecretsManagerError::ThrottlingError,
+        _ => SecretsManagerError::request_failure(err),
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Defines the `HealthCheckableSecretClient` trait for clients whose backend can be probed.
+//!
+//! Liveness probes and `/health` endpoints use this trait to check that the secret
+//! backend is reachable, without retrieving any secret value.
+
+use crate::SecretClient;
+#[cfg(any(test, feature = "mocks"))]
+use crate::{SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+
+/// Extension trait of the secret clients able to check whether their backend is reachable.
+///
+/// Wrappers such as `CachedSecretClient` implement it when their inner client does,
+/// probing the backend of the inner client.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{CachedSecretClientBuilder, FakeSecretClient, HealthCheckableSecretClient};
+/// use std::time::Duration;
+///
+/// async fn health(client: &dyn HealthCheckableSecretClient) -> u16 {
+///     match client.is_available().await {
+///         true => 200,
+///         false => 503,
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// assert_eq!(health(&FakeSecretClient::new()).await, 200);
+///
+/// let cached = CachedSecretClientBuilder::new(FakeSecretClient::new(), Duration::from_secs(60)).build();
+/// assert_eq!(health(&cached).await, 200);
+/// # });
+/// ```
+#[async_trait]
+pub trait HealthCheckableSecretClient: SecretClient {
+    /// Checks whether the secret backend is reachable.
+    ///
+    /// Implementations complete within a short timeout, and report a backend
+    /// that does not answer in time as unavailable. The default implementation,
+    /// for clients without a remote backend, is always available.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        true
+    }
+}
+
+// `automock` cannot mock the `SecretClient` supertrait, so the mock is declared with `mock!`
+#[cfg(any(test, feature = "mocks"))]
+mock! {
+    pub HealthCheckableSecretClient {}
+
+    #[async_trait]
+    impl SecretClient for HealthCheckableSecretClient {
+        fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError>;
+        fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError>;
+    }
+
+    #[async_trait]
+    impl HealthCheckableSecretClient for HealthCheckableSecretClient {
+        async fn is_available(&self) -> bool;
+    }
+}
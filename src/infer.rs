@@ -0,0 +1,266 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Backend selection from environment variables.
+//!
+//! This module provides the `SecretClientBuilder` which builds the secret client
+//! selected by the `SECRET_BACKEND` environment variable, allowing twelve-factor
+//! applications to switch backends through their deployment configuration only.
+//! Whatever the backend, the built client can emit secret lifecycle events.
+
+#[cfg(feature = "azure")]
+use crate::AzureKeyVaultClientBuilder;
+#[cfg(feature = "etcd")]
+use crate::EtcdSecretClientBuilder;
+#[cfg(feature = "file-backend")]
+use crate::FileSecretClient;
+#[cfg(feature = "gcp")]
+use crate::GcpSecretClientBuilder;
+#[cfg(feature = "k8s")]
+use crate::KubeSecretClientBuilder;
+#[cfg(feature = "redis")]
+use crate::RedisSecretClientBuilder;
+#[cfg(feature = "vault_client")]
+use crate::VaultSecretClientBuilder;
+use crate::{
+    AWSSecretClientBuilder, EnvSecretClient, EventSecretClient, SecretClient, SecretEvent,
+    errors::SecretsManagerError,
+};
+use std::env;
+#[cfg(feature = "file-backend")]
+use std::path::PathBuf;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{error, info};
+
+/// The variable selecting the backend.
+const BACKEND_VAR: &str = "SECRET_BACKEND";
+
+/// Builder of a secret client configured entirely through environment variables.
+///
+/// `SECRET_BACKEND` selects the backend, and the backend reads its settings from
+/// the variables below. `SECRET_KEY` names the secret, or the group of secrets,
+/// to read from the backend.
+///
+/// | `SECRET_BACKEND` | Variables | Feature |
+/// |---|---|---|
+/// | `aws` | `SECRET_KEY`, the region and credentials are read by the AWS SDK | |
+/// | `env` | `SECRET_ENV_PREFIX`, optional | |
+/// | `file` | `SECRET_FILE`, a `.json`, `.toml` or `.env` file | `file-backend` |
+/// | `vault` | `VAULT_ADDR`, `VAULT_TOKEN`, `VAULT_MOUNT` (`secret` if unset), `SECRET_KEY` as path | `vault_client` |
+/// | `azure` | `AZURE_KEYVAULT_URL`, `AZURE_TENANT_ID`, `AZURE_CLIENT_ID`, `AZURE_CLIENT_SECRET` | `azure` |
+/// | `gcp` | `GOOGLE_CLOUD_PROJECT`, `SECRET_KEY` | `gcp` |
+/// | `k8s` | `SECRET_NAMESPACE`, `SECRET_KEY` as the `Secret` name | `k8s` |
+/// | `redis` | `REDIS_URL`, `SECRET_KEY` as the hash key | `redis` |
+/// | `etcd` | `ETCD_ENDPOINT`, `SECRET_KEY` as the listed prefix, optional | `etcd` |
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::SecretClientBuilder;
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     // e.g. SECRET_BACKEND=aws SECRET_KEY=infra/db
+///     let client = SecretClientBuilder::infer().await?;
+///     let password = client.get_by_key("password")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// The events of every secret retrieval can be sent to a channel:
+///
+/// ```rust
+/// use secrets_manager::SecretClientBuilder;
+/// use tokio::sync::mpsc;
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let (sender, mut events) = mpsc::unbounded_channel();
+///     let client = SecretClientBuilder::new()
+///         .with_event_sink(sender)
+///         .build()
+///         .await?;
+///
+///     tokio::spawn(async move {
+///         while let Some(event) = events.recv().await {
+///             println!("{:?} {}", event.kind, event.key);
+///         }
+///     });
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretClientBuilder {
+    /// The sender the events of the built client are sent to, if any
+    event_sink: Option<UnboundedSender<SecretEvent>>,
+}
+
+impl SecretClientBuilder {
+    /// Creates a new builder of the client selected by the environment.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SecretClientBuilder`
+    #[must_use = "call `.build()` to create the client"]
+    pub fn new() -> SecretClientBuilder {
+        SecretClientBuilder::default()
+    }
+
+    /// Emits a `SecretEvent` for every secret retrieved through the built client.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The sender the events are sent to
+    ///
+    /// # Returns
+    ///
+    /// The builder with the event sink configured
+    pub fn with_event_sink(mut self, sender: UnboundedSender<SecretEvent>) -> SecretClientBuilder {
+        self.event_sink = Some(sender);
+        self
+    }
+
+    /// Builds the secret client selected by the `SECRET_BACKEND` environment variable.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Box<dyn SecretClient>)` - The client of the selected backend
+    /// * `Err(SecretsManagerError::NotSupported)` - If the backend is unknown, or
+    ///   its feature is not enabled
+    /// * `Err(SecretsManagerError::InternalError)` - If a required variable is not set
+    /// * `Err(SecretsManagerError)` - If the backend client couldn't be built
+    #[must_use = "this future must be awaited"]
+    pub async fn build(self) -> Result<Box<dyn SecretClient>, SecretsManagerError> {
+        let client = SecretClientBuilder::infer().await?;
+
+        Ok(match self.event_sink {
+            Some(sink) => Box::new(EventSecretClient::new(client, sink)),
+            None => client,
+        })
+    }
+
+    /// Builds the secret client selected by the `SECRET_BACKEND` environment variable.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Box<dyn SecretClient>)` - The client of the selected backend
+    /// * `Err(SecretsManagerError::NotSupported)` - If the backend is unknown, or
+    ///   its feature is not enabled
+    /// * `Err(SecretsManagerError::InternalError)` - If a required variable is not set
+    /// * `Err(SecretsManagerError)` - If the backend client couldn't be built
+    pub async fn infer() -> Result<Box<dyn SecretClient>, SecretsManagerError> {
+        let backend = required(BACKEND_VAR)?.to_lowercase();
+        info!(backend = backend, "building secret client from environment");
+
+        match backend.as_str() {
+            "aws" => {
+                let client = AWSSecretClientBuilder::new(required("SECRET_KEY")?)
+                    .build()
+                    .await?;
+                Ok(Box::new(client))
+            }
+            "env" => {
+                let prefix = env::var("SECRET_ENV_PREFIX").unwrap_or_default();
+                Ok(Box::new(EnvSecretClient::with_prefix(&prefix)))
+            }
+            #[cfg(feature = "file-backend")]
+            "file" => Ok(Box::new(file_client()?)),
+            #[cfg(feature = "vault_client")]
+            "vault" => {
+                let mount = env::var("VAULT_MOUNT").unwrap_or_else(|_| "secret".to_string());
+                let client = VaultSecretClientBuilder::new(
+                    &required("VAULT_ADDR")?,
+                    &required("VAULT_TOKEN")?,
+                    &mount,
+                    &required("SECRET_KEY")?,
+                )
+                .build()
+                .await?;
+                Ok(Box::new(client))
+            }
+            #[cfg(feature = "azure")]
+            "azure" => {
+                let client = AzureKeyVaultClientBuilder::new(
+                    &required("AZURE_KEYVAULT_URL")?,
+                    &required("AZURE_TENANT_ID")?,
+                    &required("AZURE_CLIENT_ID")?,
+                    &required("AZURE_CLIENT_SECRET")?,
+                )
+                .build()?;
+                Ok(Box::new(client))
+            }
+            #[cfg(feature = "gcp")]
+            "gcp" => {
+                let client = GcpSecretClientBuilder::new(
+                    &required("GOOGLE_CLOUD_PROJECT")?,
+                    &required("SECRET_KEY")?,
+                )
+                .build()
+                .await?;
+                Ok(Box::new(client))
+            }
+            #[cfg(feature = "k8s")]
+            "k8s" => {
+                let client = KubeSecretClientBuilder::new(
+                    &required("SECRET_NAMESPACE")?,
+                    &required("SECRET_KEY")?,
+                )
+                .build()
+                .await?;
+                Ok(Box::new(client))
+            }
+            #[cfg(feature = "redis")]
+            "redis" => {
+                let client = RedisSecretClientBuilder::new(
+                    &required("REDIS_URL")?,
+                    &required("SECRET_KEY")?,
+                )
+                .build()?;
+                Ok(Box::new(client))
+            }
+            #[cfg(feature = "etcd")]
+            "etcd" => {
+                let prefix = env::var("SECRET_KEY").unwrap_or_default();
+                let client = EtcdSecretClientBuilder::new(&required("ETCD_ENDPOINT")?)
+                    .prefix(&prefix)
+                    .build()
+                    .await?;
+                Ok(Box::new(client))
+            }
+            _ => {
+                error!(
+                    backend = backend,
+                    "secret backend {} is not supported", backend
+                );
+                Err(SecretsManagerError::NotSupported)
+            }
+        }
+    }
+}
+
+/// Reads a variable the selected backend cannot be built without.
+fn required(name: &str) -> Result<String, SecretsManagerError> {
+    env::var(name).map_err(|_| {
+        error!(variable = name, "environment variable {} is not set", name);
+        SecretsManagerError::internal(format!("environment variable {name} is not set"))
+    })
+}
+
+/// Loads the file named by `SECRET_FILE`, in the format given by its extension.
+#[cfg(feature = "file-backend")]
+fn file_client() -> Result<FileSecretClient, SecretsManagerError> {
+    let path = PathBuf::from(required("SECRET_FILE")?);
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => FileSecretClient::from_json_file(&path),
+        Some("toml") => FileSecretClient::from_toml_file(&path),
+        Some("env") => FileSecretClient::from_dotenv_file(&path),
+        // A file named `.env` has no extension
+        None if path.ends_with(".env") => FileSecretClient::from_dotenv_file(&path),
+        _ => {
+            error!(path = %path.display(), "secrets file {} has an unknown format", path.display());
+            Err(SecretsManagerError::NotSupported)
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Defines the `InjectSecrets` trait for populating config structs from a secret client.
+//!
+//! Implementations are usually generated with `#[derive(InjectSecrets)]`, available
+//! behind the `derive` feature. Alternatively, `from_client` deserializes the
+//! secrets into any `serde` deserializable struct.
+
+use crate::{SecretClient, errors::SecretsManagerError};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::error;
+
+/// Trait for types that can be built from the secrets of a `SecretClient`.
+///
+/// With the `derive` feature, `#[derive(InjectSecrets)]` generates this
+/// implementation from `#[secret("key-name")]` field attributes. `String` fields
+/// require the secret to exist, while `Option<String>` fields are `None` when
+/// the secret is not found.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{FakeSecretClient, InjectSecrets, SecretClient, errors::SecretsManagerError};
+/// use std::collections::HashMap;
+///
+/// struct DatabaseConfig {
+///     password: String,
+///     replica_password: Option<String>,
+/// }
+///
+/// // Equivalent to what `#[derive(InjectSecrets)]` generates for
+/// // `#[secret("db_pass")] password` and `#[secret("replica_pass")] replica_password`
+/// impl InjectSecrets for DatabaseConfig {
+///     fn from_secrets(client: &dyn SecretClient) -> Result<Self, SecretsManagerError> {
+///         Ok(DatabaseConfig {
+///             password: client.get_by_key("db_pass")?.into_string(),
+///             replica_password: client.get_optional("replica_pass")?.map(|v| v.into_string()),
+///         })
+///     }
+/// }
+///
+/// let client = FakeSecretClient::with_secrets(HashMap::from([(
+///     "db_pass".to_string(),
+///     "secret".to_string(),
+/// )]));
+///
+/// let config = DatabaseConfig::from_secrets(&client).unwrap();
+/// assert_eq!(config.password, "secret");
+/// assert_eq!(config.replica_password, None);
+/// ```
+pub trait InjectSecrets: Sized {
+    /// Builds the value by reading its secrets from the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client the secrets are read from
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - If every required secret was retrieved
+    /// * `Err(SecretsManagerError)` - If a secret couldn't be retrieved
+    fn from_secrets(client: &dyn SecretClient) -> Result<Self, SecretsManagerError>;
+}
+
+/// Deserializes the secrets of a client into a struct, like `envy::from_env` does
+/// with environment variables.
+///
+/// Every secret returned by `get_all` becomes a string field of a JSON object,
+/// which is then deserialized into `T`. Fields are matched by key, so they can be
+/// renamed with `#[serde(rename = "...")]`, and missing secrets only fail for
+/// fields that are neither `Option` nor `#[serde(default)]`.
+///
+/// # Arguments
+///
+/// * `client` - The client the secrets are read from
+///
+/// # Returns
+///
+/// * `Ok(T)` - The deserialized value
+/// * `Err(SecretsManagerError::DeserializationError)` - If the secrets don't match `T`
+/// * `Err(SecretsManagerError)` - If the secrets couldn't be retrieved
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use std::collections::HashMap;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(rename = "db-pass")]
+///     database_password: String,
+///     api_key: Option<String>,
+/// }
+///
+/// let client = HashMap::from([("db-pass", "secret")]);
+/// let config: Config = secrets_manager::from_client(&client).unwrap();
+///
+/// assert_eq!(config.database_password, "secret");
+/// assert_eq!(config.api_key, None);
+/// ```
+pub fn from_client<T: DeserializeOwned>(
+    client: &dyn SecretClient,
+) -> Result<T, SecretsManagerError> {
+    let secrets = client
+        .get_all()?
+        .into_iter()
+        .map(|(key, value)| (key, Value::String(value.into_string())))
+        .collect();
+
+    serde_json::from_value(Value::Object(secrets)).map_err(|err| {
+        error!(error = err.to_string(), "error deserializing secrets");
+        SecretsManagerError::DeserializationError(err.to_string())
+    })
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Kubernetes secret client implementation.
+//!
+//! This module provides the `KubeSecretClient` which implements the `SecretClient` trait
+//! for retrieving the entries of a Kubernetes `v1/Secret` object.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError, lookup::not_found};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use std::collections::HashMap;
+
+/// The name of the backend, as logged when a secret is not found.
+const BACKEND: &str = "Kubernetes secret";
+
+/// Client for accessing the entries of a Kubernetes secret.
+///
+/// This client stores the decoded entries of a `v1/Secret` in memory after
+/// they've been retrieved from the API server and provides methods to access
+/// them by key.
+#[derive(Default)]
+pub struct KubeSecretClient {
+    /// The decoded secret entries
+    pub(crate) secrets: HashMap<String, String>,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl SecretClient for KubeSecretClient {
+    /// Retrieves a secret from the decoded secret entries by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let Some(secret) = self.secrets.get(key) else {
+            return Err(not_found(key, BACKEND));
+        };
+
+        Ok(SecretValue::from(secret.clone()))
+    }
+
+    /// Lists the keys of the decoded secret entries.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self.secrets.keys().cloned().collect())
+    }
+}
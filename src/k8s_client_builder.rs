@@ -0,0 +1,157 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Kubernetes secret client builder module.
+//!
+//! This module provides the `KubeSecretClientBuilder` for constructing instances of
+//! `KubeSecretClient` with appropriate configuration.
+
+use crate::{KubeSecretClient, errors::SecretsManagerError};
+use k8s_openapi::api::core::v1::Secret;
+use kube::{
+    Api, Client, Config,
+    config::{KubeConfigOptions, Kubeconfig},
+};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use tracing::error;
+
+/// Builder for Kubernetes secret client instances.
+///
+/// This builder facilitates the creation of `KubeSecretClient` instances by
+/// handling the cluster configuration and the retrieval of the `v1/Secret`.
+/// The configuration is inferred from the environment, which uses the
+/// in-cluster service account when running in a pod, unless a kubeconfig
+/// path is given.
+#[derive(Default)]
+pub struct KubeSecretClientBuilder {
+    /// The namespace of the secret
+    namespace: String,
+    /// The name of the secret
+    name: String,
+    /// The path of the kubeconfig file overriding the inferred configuration
+    kubeconfig_path: Option<String>,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl KubeSecretClientBuilder {
+    /// Creates a new builder for the given Kubernetes secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace of the secret
+    /// * `name` - The name of the secret
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `KubeSecretClientBuilder`
+    pub fn new(namespace: &str, name: &str) -> KubeSecretClientBuilder {
+        KubeSecretClientBuilder {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            kubeconfig_path: None,
+        }
+    }
+
+    /// Reads the cluster configuration from a kubeconfig file, e.g. for local development.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the kubeconfig file
+    ///
+    /// # Returns
+    ///
+    /// The builder with the kubeconfig path configured
+    pub fn kubeconfig_path(mut self, path: &str) -> KubeSecretClientBuilder {
+        self.kubeconfig_path = Some(path.to_string());
+        self
+    }
+
+    /// Builds and returns a `KubeSecretClient` instance.
+    ///
+    /// This asynchronous method:
+    /// 1. Loads the cluster configuration
+    /// 2. Reads the `v1/Secret`
+    /// 3. Creates a `KubeSecretClient` with the decoded entries
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(KubeSecretClient)` - If the secret was successfully retrieved
+    /// * `Err(SecretsManagerError)` - If any step failed
+    pub async fn build(&self) -> Result<KubeSecretClient, SecretsManagerError> {
+        let config = match self.config().await {
+            Err(err) => {
+                error!(error = err, "failure to load kubernetes config");
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(c) => Ok(c),
+        }?;
+
+        let client = match Client::try_from(config) {
+            Err(err) => {
+                error!(
+                    error = err.to_string(),
+                    "failure to create kubernetes client"
+                );
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(c) => Ok(c),
+        }?;
+
+        let secret = match Api::<Secret>::namespaced(client, &self.namespace)
+            .get(&self.name)
+            .await
+        {
+            Err(kube::Error::Api(status)) if status.code == 404 => {
+                let key = format!("{}/{}", self.namespace, self.name);
+                error!(key = key, "kubernetes secret {} was not found", key);
+                Err(SecretsManagerError::SecretNotFound { key })
+            }
+            Err(kube::Error::Api(status)) if status.code == 429 => {
+                error!("request to kubernetes was throttled");
+                Err(SecretsManagerError::ThrottlingError {})
+            }
+            Err(err) => {
+                error!(
+                    error = err.to_string(),
+                    "failure send request to kubernetes"
+                );
+                Err(SecretsManagerError::request_failure(err))
+            }
+            Ok(s) => Ok(s),
+        }?;
+
+        // The API server returns base64 values, which are decoded when deserializing the secret
+        let secrets = secret
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| match String::from_utf8(value.0) {
+                Err(err) => {
+                    error!(key = key, "secret {} is not valid UTF-8", key);
+                    Err(SecretsManagerError::DeserializationError(err.to_string()))
+                }
+                Ok(v) => Ok((key, v)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(KubeSecretClient { secrets })
+    }
+
+    /// Loads the kubeconfig file if configured, or infers the configuration otherwise.
+    async fn config(&self) -> Result<Config, String> {
+        let Some(path) = &self.kubeconfig_path else {
+            return Config::infer().await.map_err(|err| err.to_string());
+        };
+
+        let kubeconfig = Kubeconfig::read_from(path).map_err(|err| err.to_string())?;
+
+        Config::from_custom_kubeconfig(kubeconfig, &KubeConfigOptions::default())
+            .await
+            .map_err(|err| err.to_string())
+    }
+}
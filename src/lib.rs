@@ -11,9 +11,44 @@
 //! ## Features
 //!
 //! - Trait-based API design for flexibility and testability
-//! - AWS Secrets Manager integration
+//! - AWS Secrets Manager integration, including binary secrets
+//! - HashiCorp Vault integration behind the `vault_client` feature
+//! - Azure Key Vault integration behind the `azure` feature
+//! - Google Cloud Secret Manager integration behind the `gcp` feature
+//! - Kubernetes secrets behind the `k8s` feature
+//! - Redis hashes behind the `redis` feature
+//! - etcd key-value pairs behind the `etcd` feature
+//! - Environment variable client for local development
+//! - Backend selection from the `SECRET_BACKEND` environment variable
+//! - Builders of every backend usable as a `Box<dyn SecretClientFactory>`
+//! - JSON, TOML and `.env` file client behind the `file-backend` feature
+//! - SOPS-encrypted JSON files behind the `sops` feature
+//! - TTL-based caching wrapper for any client, with explicit invalidation, cache statistics
+//!   and per-key retry budgets
+//! - Health checks of the secret backend for liveness probes
+//! - Circuit breaker failing fast during backend outages
+//! - Per-key and global rate limiting behind the `rate-limit` feature
+//! - Fallback chain trying several clients in order
+//! - Aggregation of several AWS secrets into a single client
+//! - Namespaces scoping secrets by environment or service
+//! - Audit hooks recording every secret access
+//! - Secret lifecycle events sent to a channel
+//! - Rotation listener broadcasting secret changes
+//! - Streams of secret changes behind the `stream` feature
+//! - OpenTelemetry trace propagation to AWS behind the `opentelemetry` feature
+//! - `tower` middleware sharing a client with request handlers behind the `tower` feature
+//! - axum extractor injecting secrets into handlers behind the `axum` feature
+//! - `config` crate source behind the `config-source` feature
+//! - `figment` provider behind the `figment-provider` feature
+//! - `#[derive(InjectSecrets)]` for config structs behind the `derive` feature
+//! - `envy`-style deserialization of config structs from the secrets of a client
 //! - Mock implementations for testing
-//! - Comprehensive error handling
+//! - In-memory client mutable at runtime for stateful tests
+//! - Immutable snapshots of the secrets of a client for request-scoped consistency
+//! - Dry-run validation of required secrets behind the `dry-run` feature
+//! - Retrieval of all the required secrets at once, reporting every missing one
+//! - Secret values zeroed from memory on drop
+//! - Comprehensive error handling, serializable behind the `serde` feature
 //!
 //! ## Example
 //!
@@ -33,13 +68,141 @@
 //! }
 //! ```
 
+#[cfg(feature = "metrics")]
+mod access_metrics;
+mod audit;
 mod aws_client;
 mod aws_client_builder;
+#[cfg(feature = "axum")]
+mod axum_extract;
+#[cfg(feature = "azure")]
+mod azure_client;
+#[cfg(feature = "azure")]
+mod azure_client_builder;
+mod binary_client;
+#[cfg(any(feature = "azure", feature = "etcd"))]
+mod blocking;
+mod cacheable_client;
+mod cached_client;
+mod circuit_breaker;
 mod client;
+mod client_ext;
+mod client_factory;
+#[cfg(feature = "config-source")]
+mod config_source;
+#[cfg(feature = "dry-run")]
+mod dry_run;
+mod env_client;
+#[cfg(feature = "etcd")]
+mod etcd_client;
+#[cfg(feature = "etcd")]
+mod etcd_client_builder;
+mod events;
 mod fake_client;
+mod fallback_client;
+#[cfg(feature = "figment-provider")]
+mod figment_provider;
+#[cfg(feature = "file-backend")]
+mod file_client;
+#[cfg(feature = "gcp")]
+mod gcp_client;
+#[cfg(feature = "gcp")]
+mod gcp_client_builder;
+mod health_check;
+mod infer;
+mod inject;
+#[cfg(feature = "k8s")]
+mod k8s_client;
+#[cfg(feature = "k8s")]
+mod k8s_client_builder;
+mod lookup;
+mod memory_client;
+mod multi_client;
+mod namespace;
+mod prefix_stripping_client;
+#[cfg(feature = "rate-limit")]
+mod rate_limit;
+#[cfg(feature = "redis")]
+mod redis_client;
+#[cfg(feature = "redis")]
+mod redis_client_builder;
+mod role_credentials;
+mod rotation_listener;
+mod secret_value;
+mod snapshot;
+#[cfg(feature = "tower")]
+mod tower_layer;
+#[cfg(feature = "opentelemetry")]
+mod trace_context;
+#[cfg(feature = "vault_client")]
+mod vault_client;
+#[cfg(feature = "vault_client")]
+mod vault_client_builder;
+#[cfg(feature = "stream")]
+mod watch;
 
 pub mod errors;
-pub use aws_client::AWSSecretClient;
-pub use aws_client_builder::AWSSecretClientBuilder;
+pub use audit::{AuditEvent, AuditHook, AuditedSecretClient, ChannelAuditHook, StdoutAuditHook};
+pub use aws_client::{AWSSecretClient, SecretMetadata};
+pub use aws_client_builder::{AWSSecretClientBuilder, KeyPrefix, KeyTransform, WarmingStrategy};
+#[cfg(feature = "axum")]
+pub use axum_extract::{InjectableSecret, SecretKey, SecretRejection};
+#[cfg(feature = "azure")]
+pub use azure_client::AzureKeyVaultClient;
+#[cfg(feature = "azure")]
+pub use azure_client_builder::AzureKeyVaultClientBuilder;
+pub use binary_client::BinarySecretClient;
+pub use cacheable_client::CacheableSecretClient;
+pub use cached_client::{CacheStats, CachedSecretClient, CachedSecretClientBuilder, RetryBudget};
+pub use circuit_breaker::CircuitBreakerSecretClient;
 pub use client::SecretClient;
-pub use fake_client::FakeSecretClient;
+pub use client_ext::SecretClientExt;
+pub use client_factory::SecretClientFactory;
+#[cfg(feature = "dry-run")]
+pub use dry_run::DryRunSecretClient;
+pub use env_client::EnvSecretClient;
+#[cfg(feature = "etcd")]
+pub use etcd_client::EtcdSecretClient;
+#[cfg(feature = "etcd")]
+pub use etcd_client_builder::EtcdSecretClientBuilder;
+pub use events::{EventSecretClient, SecretEvent, SecretEventKind};
+pub use fake_client::{FakeBinarySecretClient, FakeSecretClient, FakeSecretClientBuilder};
+pub use fallback_client::FallbackSecretClient;
+#[cfg(feature = "file-backend")]
+pub use file_client::FileSecretClient;
+#[cfg(feature = "gcp")]
+pub use gcp_client::GcpSecretClient;
+#[cfg(feature = "gcp")]
+pub use gcp_client_builder::GcpSecretClientBuilder;
+pub use health_check::HealthCheckableSecretClient;
+pub use infer::SecretClientBuilder;
+pub use inject::{InjectSecrets, from_client};
+#[cfg(feature = "k8s")]
+pub use k8s_client::KubeSecretClient;
+#[cfg(feature = "k8s")]
+pub use k8s_client_builder::KubeSecretClientBuilder;
+pub use memory_client::MemorySecretClient;
+pub use multi_client::MultiSecretClient;
+pub use namespace::SecretNamespace;
+pub use prefix_stripping_client::PrefixStrippingSecretClient;
+#[cfg(feature = "rate-limit")]
+pub use rate_limit::{RateLimitedSecretClient, RateLimitedSecretClientBuilder};
+#[cfg(feature = "redis")]
+pub use redis_client::RedisSecretClient;
+#[cfg(feature = "redis")]
+pub use redis_client_builder::RedisSecretClientBuilder;
+pub use rotation_listener::{SecretChangedEvent, SecretRotationListener};
+pub use secret_value::SecretValue;
+#[cfg(feature = "derive")]
+pub use secrets_manager_derive::InjectSecrets;
+#[cfg(all(feature = "derive", feature = "axum"))]
+pub use secrets_manager_derive::SecretKey;
+pub use snapshot::SecretSnapshot;
+#[cfg(feature = "tower")]
+pub use tower_layer::{SecretManagerLayer, SecretManagerService};
+#[cfg(feature = "vault_client")]
+pub use vault_client::VaultSecretClient;
+#[cfg(feature = "vault_client")]
+pub use vault_client_builder::VaultSecretClientBuilder;
+#[cfg(feature = "stream")]
+pub use watch::WatchableSecretClient;
@@ -0,0 +1,105 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Lookup of secrets by key in a JSON document.
+//!
+//! This module provides the lookups shared by the clients whose backend returns
+//! its secrets as a JSON document, such as the AWS and GCP clients. The backend
+//! is named in the logs, so that a missing key can be traced to its source.
+
+use crate::{SecretValue, errors::SecretsManagerError};
+use serde_json::Value;
+use tracing::warn;
+
+/// Looks up a secret by its key in the given secrets.
+///
+/// See [`resolve`] for how dot-separated keys are handled.
+///
+/// # Arguments
+///
+/// * `secrets` - The secrets retrieved from the backend
+/// * `key` - The key identifying the secret to retrieve
+/// * `backend` - The name of the backend the secrets were retrieved from
+///
+/// # Returns
+///
+/// * `Ok(SecretValue)` - The secret value
+/// * `Err(SecretsManagerError::SecretNotFound)` - If no string value has the key
+pub(crate) fn lookup(
+    secrets: &Value,
+    key: &str,
+    backend: &str,
+) -> Result<SecretValue, SecretsManagerError> {
+    let Some(Value::String(secret)) = resolve(secrets, key) else {
+        return Err(not_found(key, backend));
+    };
+
+    Ok(SecretValue::from(secret.clone()))
+}
+
+/// Looks up a value of any JSON type by its key in the given secrets.
+///
+/// Keys are resolved as by [`resolve`], without requiring a string value.
+///
+/// # Arguments
+///
+/// * `secrets` - The secrets retrieved from the backend
+/// * `key` - The key identifying the value to retrieve
+/// * `backend` - The name of the backend the secrets were retrieved from
+///
+/// # Returns
+///
+/// * `Ok(&Value)` - The value
+/// * `Err(SecretsManagerError::SecretNotFound)` - If no value has the key
+pub(crate) fn lookup_value<'a>(
+    secrets: &'a Value,
+    key: &str,
+    backend: &str,
+) -> Result<&'a Value, SecretsManagerError> {
+    let value = secrets.get(key).or_else(|| {
+        key.split('.')
+            .try_fold(secrets, |value, segment| value.get(segment))
+    });
+
+    value.ok_or_else(|| not_found(key, backend))
+}
+
+/// Resolves a key to a string value in the given secrets.
+///
+/// The key is first looked up verbatim in the top-level object, so keys that
+/// literally contain dots keep working. Only when that fails is the key treated
+/// as a dot-separated path, e.g. `db.password` resolves `secrets["db"]["password"]`.
+pub(crate) fn resolve<'a>(secrets: &'a Value, key: &str) -> Option<&'a Value> {
+    if let Some(value) = secrets.get(key).filter(|v| v.is_string()) {
+        return Some(value);
+    }
+
+    key.split('.')
+        .try_fold(secrets, |value, segment| value.get(segment))
+        .filter(|v| v.is_string())
+}
+
+/// Builds the error reported when the requested key is missing from a backend.
+///
+/// # Arguments
+///
+/// * `key` - The key that was requested
+/// * `backend` - The name of the backend the key is missing from
+///
+/// # Returns
+///
+/// A `SecretsManagerError::SecretNotFound` naming the requested key
+pub(crate) fn not_found(key: &str, backend: &str) -> SecretsManagerError {
+    warn!(
+        key = key,
+        backend = backend,
+        "secret {} was not found in {}",
+        key,
+        backend
+    );
+
+    SecretsManagerError::SecretNotFound {
+        key: key.to_string(),
+    }
+}
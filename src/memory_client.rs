@@ -0,0 +1,204 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! In-memory secret client implementation for stateful tests.
+//!
+//! This module provides a `MemorySecretClient` that implements the `SecretClient` trait
+//! over an in-memory map which, unlike the one of `FakeSecretClient`, can be mutated
+//! after construction.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{PoisonError, RwLock},
+};
+use tracing::error;
+
+/// An in-memory implementation of the `SecretClient` trait that can be mutated at runtime.
+///
+/// Secrets can be inserted, removed and cleared through a shared reference while the
+/// client is in use, which makes it the recommended test double for stateful tests
+/// such as property-based or fuzz tests. Prefer `FakeSecretClient` when the secrets
+/// are fixed for the whole test.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{MemorySecretClient, SecretClient, errors::SecretsManagerError};
+/// use std::collections::HashMap;
+///
+/// let client = MemorySecretClient::new();
+/// client.insert("api-key", "value");
+/// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+///
+/// client.insert("db-pass", "secret");
+/// client.remove("api-key");
+/// assert_eq!(
+///     client.get_by_key("api-key"),
+///     Err(SecretsManagerError::SecretNotFound { key: "api-key".to_string() })
+/// );
+/// assert_eq!(
+///     client.snapshot(),
+///     HashMap::from([("db-pass".to_string(), "secret".to_string())])
+/// );
+///
+/// client.clear();
+/// assert!(client.snapshot().is_empty());
+/// ```
+#[derive(Default)]
+pub struct MemorySecretClient {
+    /// The stored secrets
+    secrets: RwLock<HashMap<String, String>>,
+}
+
+impl fmt::Debug for MemorySecretClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secrets = self.secrets.read().unwrap_or_else(PoisonError::into_inner);
+
+        f.debug_struct("MemorySecretClient")
+            .field("keys", &secrets.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl SecretClient for MemorySecretClient {
+    /// Retrieves a secret from the stored secrets by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The stored value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the key is absent
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let secrets = self.secrets.read().unwrap_or_else(PoisonError::into_inner);
+
+        let Some(secret) = secrets.get(key) else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(SecretValue::from(secret.clone()))
+    }
+
+    /// Checks whether the stored secrets hold the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key is stored, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.secrets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(key)
+    }
+
+    /// Retrieves all stored secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - The stored secrets
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        Ok(self
+            .snapshot()
+            .into_iter()
+            .map(|(key, value)| (key, SecretValue::from(value)))
+            .collect())
+    }
+
+    /// Lists the keys of the stored secrets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The stored keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self
+            .secrets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect())
+    }
+}
+
+impl MemorySecretClient {
+    /// Creates a new empty `MemorySecretClient` instance.
+    ///
+    /// # Returns
+    ///
+    /// A new `MemorySecretClient`
+    pub fn new() -> MemorySecretClient {
+        MemorySecretClient::default()
+    }
+
+    /// Creates a new `MemorySecretClient` seeded with the given secrets.
+    ///
+    /// # Arguments
+    ///
+    /// * `secrets` - The key-value pairs the client should initially serve
+    ///
+    /// # Returns
+    ///
+    /// A new `MemorySecretClient`
+    pub fn with_secrets(secrets: HashMap<String, String>) -> MemorySecretClient {
+        MemorySecretClient {
+            secrets: RwLock::new(secrets),
+        }
+    }
+
+    /// Stores a secret, replacing any previous value of the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the secret
+    /// * `value` - The value returned for the key
+    pub fn insert(&self, key: &str, value: &str) {
+        self.secrets
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_string(), value.to_string());
+    }
+
+    /// Removes a secret, if stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the secret
+    pub fn remove(&self, key: &str) {
+        self.secrets
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(key);
+    }
+
+    /// Removes every stored secret.
+    pub fn clear(&self) {
+        self.secrets
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Returns a copy of the stored secrets, for assertions.
+    ///
+    /// # Returns
+    ///
+    /// The key-value pairs stored at the time of the call
+    pub fn snapshot(&self) -> HashMap<String, String> {
+        self.secrets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
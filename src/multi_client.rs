@@ -0,0 +1,215 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Aggregating secret client implementation.
+//!
+//! This module provides the `MultiSecretClient` which merges the key spaces of
+//! several `SecretClient` implementations, typically one per AWS secret object
+//! such as `infra/db` and `infra/redis`.
+
+use crate::{AWSSecretClientBuilder, SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use tokio::task::JoinSet;
+use tracing::warn;
+
+/// Client that merges the key spaces of several secret clients.
+///
+/// When several clients hold the same key, the client that comes last in the
+/// list wins. Collisions between clients able to list their keys are reported
+/// with a warning at construction.
+pub struct MultiSecretClient {
+    /// The merged clients, in increasing order of precedence
+    clients: Vec<Box<dyn SecretClient>>,
+}
+
+#[async_trait]
+impl SecretClient for MultiSecretClient {
+    /// Retrieves a secret from the last client holding its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client holds the key
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.last_found(key, |client| client.get_by_key(key))
+    }
+
+    /// Checks whether any client holds the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is present, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.clients.iter().any(|client| client.contains_key(key))
+    }
+
+    /// Lists the merged keys of all clients.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The deduplicated keys of all clients
+    /// * `Err(SecretsManagerError)` - If any client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        let mut keys = Vec::new();
+
+        for client in &self.clients {
+            keys.extend(client.list_keys()?);
+        }
+
+        keys.sort();
+        keys.dedup();
+
+        Ok(keys)
+    }
+
+    /// Retrieves a secret asynchronously from the last client holding its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client holds the key
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        for client in self.clients.iter().rev() {
+            match client.get_by_key_async(key).await {
+                Err(SecretsManagerError::SecretNotFound { .. }) => continue,
+                result => return result,
+            }
+        }
+
+        Err(SecretsManagerError::SecretNotFound {
+            key: key.to_string(),
+        })
+    }
+
+    /// Retrieves a secret as a JSON object from the last client holding its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client holds the key
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.last_found(key, |client| client.get_json_object_by_key(key))
+    }
+
+    /// Retrieves a secret as a JSON array from the last client holding its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If no client holds the key
+    /// * `Err(SecretsManagerError)` - The first error that is not `SecretNotFound`
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.last_found(key, |client| client.get_json_array_by_key(key))
+    }
+}
+
+impl MultiSecretClient {
+    /// Creates a new `MultiSecretClient` merging the given clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `clients` - The clients to merge, in increasing order of precedence
+    ///
+    /// # Returns
+    ///
+    /// A new `MultiSecretClient`
+    pub fn new(clients: Vec<Box<dyn SecretClient>>) -> MultiSecretClient {
+        let mut seen = HashSet::new();
+
+        for client in &clients {
+            for key in client.list_keys().unwrap_or_default() {
+                if !seen.insert(key.clone()) {
+                    warn!(key = key, "secret {} is defined by several clients", key);
+                }
+            }
+        }
+
+        MultiSecretClient { clients }
+    }
+
+    /// Builds every AWS client concurrently, on tasks of the current `tokio`
+    /// runtime, and merges them.
+    ///
+    /// # Arguments
+    ///
+    /// * `builders` - The builders of the AWS clients, in increasing order of precedence
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MultiSecretClient)` - If every client was successfully built
+    /// * `Err(SecretsManagerError)` - The first error encountered, in builder order
+    pub async fn build(
+        builders: Vec<AWSSecretClientBuilder>,
+    ) -> Result<MultiSecretClient, SecretsManagerError> {
+        let mut tasks = JoinSet::new();
+        for (index, builder) in builders.into_iter().enumerate() {
+            tasks.spawn(async move { (index, builder.build().await) });
+        }
+
+        let mut results = tasks.join_all().await;
+        results.sort_by_key(|(index, _)| *index);
+
+        let clients = results
+            .into_iter()
+            .map(|(_, result)| result.map(|client| Box::new(client) as Box<dyn SecretClient>))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(MultiSecretClient::new(clients))
+    }
+
+    /// Returns the result of the last client holding the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    /// * `get` - The retrieval of the secret from a client
+    ///
+    /// # Returns
+    ///
+    /// The result of the last client not reporting `SecretNotFound`, or
+    /// `SecretNotFound` if no client holds the key
+    fn last_found<T>(
+        &self,
+        key: &str,
+        get: impl Fn(&dyn SecretClient) -> Result<T, SecretsManagerError>,
+    ) -> Result<T, SecretsManagerError> {
+        for client in self.clients.iter().rev() {
+            match get(client.as_ref()) {
+                Err(SecretsManagerError::SecretNotFound { .. }) => continue,
+                result => return result,
+            }
+        }
+
+        Err(SecretsManagerError::SecretNotFound {
+            key: key.to_string(),
+        })
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Namespaced secret client implementation.
+//!
+//! This module provides the `SecretNamespace` which scopes every key of an inner
+//! `SecretClient` under a configured prefix, so that each service in a monorepo
+//! only accesses its own secrets.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+
+/// Client that scopes all keys of an inner client under a namespace.
+///
+/// The namespace is prepended to every key before delegating to the inner
+/// client, and stripped from the keys returned by `list_keys`.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{FakeSecretClient, SecretClient, SecretNamespace};
+/// use std::{collections::HashMap, sync::Arc};
+///
+/// let inner = FakeSecretClient::with_secrets(HashMap::from([(
+///     "prod/payments/api-key".to_string(),
+///     "value".to_string(),
+/// )]));
+///
+/// let client = SecretNamespace::new(Arc::new(inner), "prod/payments/");
+///
+/// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+/// assert_eq!(client.list_keys().unwrap(), vec!["api-key".to_string()]);
+/// ```
+pub struct SecretNamespace {
+    /// The client the namespaced keys are delegated to
+    inner: Arc<dyn SecretClient>,
+    /// The prefix prepended to every key
+    namespace: String,
+}
+
+#[async_trait]
+impl SecretClient for SecretNamespace {
+    /// Retrieves a secret from the inner client by its namespaced key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret within the namespace
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.inner.get_by_key(&self.scoped(key))
+    }
+
+    /// Checks whether the inner client holds the namespaced key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret within the namespace
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is present, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.inner.contains_key(&self.scoped(key))
+    }
+
+    /// Lists the keys of the inner client that belong to the namespace.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys within the namespace, without the namespace prefix
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self
+            .inner
+            .list_keys()?
+            .into_iter()
+            .filter_map(|key| key.strip_prefix(&self.namespace).map(str::to_string))
+            .collect())
+    }
+
+    /// Retrieves a secret asynchronously from the inner client by its namespaced key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret within the namespace
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.inner.get_by_key_async(&self.scoped(key)).await
+    }
+
+    /// Retrieves a secret from the inner client as a JSON object by its namespaced key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret within the namespace
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.inner.get_json_object_by_key(&self.scoped(key))
+    }
+
+    /// Retrieves a secret from the inner client as a JSON array by its namespaced key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret within the namespace
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.inner.get_json_array_by_key(&self.scoped(key))
+    }
+}
+
+impl SecretNamespace {
+    /// Creates a new `SecretNamespace` wrapping the given client.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client the namespaced keys are delegated to
+    /// * `namespace` - The prefix prepended to every key, e.g. `prod/payments/`
+    ///
+    /// # Returns
+    ///
+    /// A new `SecretNamespace`
+    pub fn new(inner: Arc<dyn SecretClient>, namespace: &str) -> SecretNamespace {
+        SecretNamespace {
+            inner,
+            namespace: namespace.to_string(),
+        }
+    }
+
+    /// Returns the key prefixed with the namespace.
+    fn scoped(&self, key: &str) -> String {
+        format!("{}{}", self.namespace, key)
+    }
+}
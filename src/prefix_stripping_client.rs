@@ -0,0 +1,162 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Sigil-stripping secret client implementation.
+//!
+//! This module provides the `PrefixStrippingSecretClient` which removes a leading
+//! sigil, such as `!`, from every key before delegating to an inner `SecretClient`,
+//! so that the inner client never sees the sigil.
+
+use crate::{HealthCheckableSecretClient, SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+
+/// Client that strips a leading sigil from keys before delegating to an inner client.
+///
+/// Keys that do not start with the sigil are passed through unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{FakeSecretClient, PrefixStrippingSecretClient, SecretClient};
+/// use std::collections::HashMap;
+///
+/// let inner = FakeSecretClient::with_secrets(HashMap::from([(
+///     "api-key".to_string(),
+///     "value".to_string(),
+/// )]));
+///
+/// let client = PrefixStrippingSecretClient::new(inner, "!");
+///
+/// assert_eq!(&*client.get_by_key("!api-key").unwrap(), "value");
+/// assert_eq!(&*client.get_by_key("api-key").unwrap(), "value");
+/// ```
+pub struct PrefixStrippingSecretClient<C: SecretClient> {
+    /// The client the stripped keys are delegated to
+    inner: C,
+    /// The sigil removed from the start of every key
+    prefix: String,
+}
+
+#[async_trait]
+impl<C: SecretClient> SecretClient for PrefixStrippingSecretClient<C> {
+    /// Retrieves a secret from the inner client with the sigil removed from the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret, optionally starting with the sigil
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.inner.get_by_key(self.strip(key))
+    }
+
+    /// Checks whether the inner client holds the key with the sigil removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret, optionally starting with the sigil
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is present, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.inner.contains_key(self.strip(key))
+    }
+
+    /// Lists the keys available from the inner client.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys reported by the inner client
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.inner.list_keys()
+    }
+
+    /// Retrieves a secret asynchronously from the inner client with the sigil removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret, optionally starting with the sigil
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.inner.get_by_key_async(self.strip(key)).await
+    }
+
+    /// Retrieves a secret from the inner client as a JSON object with the sigil removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret, optionally starting with the sigil
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.inner.get_json_object_by_key(self.strip(key))
+    }
+
+    /// Retrieves a secret from the inner client as a JSON array with the sigil removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret, optionally starting with the sigil
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.inner.get_json_array_by_key(self.strip(key))
+    }
+}
+
+#[async_trait]
+impl<C: HealthCheckableSecretClient> HealthCheckableSecretClient
+    for PrefixStrippingSecretClient<C>
+{
+    /// Checks whether the backend of the inner client is reachable.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend of the inner client is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+impl<C: SecretClient> PrefixStrippingSecretClient<C> {
+    /// Creates a new `PrefixStrippingSecretClient` wrapping the given client.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client the stripped keys are delegated to
+    /// * `prefix` - The sigil removed from the start of every key, e.g. `!`
+    ///
+    /// # Returns
+    ///
+    /// A new `PrefixStrippingSecretClient`
+    pub fn new(inner: C, prefix: &str) -> PrefixStrippingSecretClient<C> {
+        PrefixStrippingSecretClient {
+            inner,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Returns the key without the leading sigil.
+    fn strip<'a>(&self, key: &'a str) -> &'a str {
+        key.strip_prefix(self.prefix.as_str()).unwrap_or(key)
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Rate-limited secret client implementation.
+//!
+//! This module provides the `RateLimitedSecretClient` which rejects the secret
+//! retrievals exceeding a configured rate, so that a bug calling `get_by_key` in a
+//! tight loop cannot exhaust the API quota of the secret backend.
+
+use crate::{HealthCheckableSecretClient, SecretClient, SecretValue, errors::SecretsManagerError};
+use async_trait::async_trait;
+use governor::{
+    DefaultDirectRateLimiter, DefaultKeyedRateLimiter, NotUntil, Quota, RateLimiter,
+    clock::{Clock, DefaultClock},
+};
+use serde_json::{Map, Value};
+use std::{num::NonZeroU32, time::Duration};
+use tracing::warn;
+
+/// Client that rejects the retrievals exceeding a rate limit.
+///
+/// A retrieval is rejected if it exceeds the limit of its key, or the limit
+/// shared by all keys, whichever are configured. Rejected retrievals fail
+/// immediately with `SecretsManagerError::RateLimited`, without reaching the
+/// inner client. Limits allow bursts of up to `max_calls` calls, and replenish
+/// them evenly over their period.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{RateLimitedSecretClientBuilder, SecretClient, errors::SecretsManagerError};
+/// use std::{collections::HashMap, time::Duration};
+///
+/// let inner = HashMap::from([("api-key", "value"), ("db-pass", "secret")]);
+/// let client = RateLimitedSecretClientBuilder::new(inner)
+///     .with_per_key_limit(2, Duration::from_secs(60))
+///     .with_global_limit(100, Duration::from_secs(1))
+///     .build();
+///
+/// assert!(client.get_by_key("api-key").is_ok());
+/// assert!(client.get_by_key("api-key").is_ok());
+/// assert!(matches!(
+///     client.get_by_key("api-key"),
+///     Err(SecretsManagerError::RateLimited { .. })
+/// ));
+///
+/// // Other keys have their own limit
+/// assert!(client.get_by_key("db-pass").is_ok());
+///
+/// // All keys share the global limit
+/// let inner = HashMap::from([("api-key", "value"), ("db-pass", "secret")]);
+/// let client = RateLimitedSecretClientBuilder::new(inner)
+///     .with_per_key_limit(2, Duration::from_secs(60))
+///     .with_global_limit(2, Duration::from_secs(60))
+///     .build();
+///
+/// assert!(client.get_by_key("api-key").is_ok());
+/// assert!(client.get_by_key("db-pass").is_ok());
+/// assert!(matches!(
+///     client.get_by_key("api-key"),
+///     Err(SecretsManagerError::RateLimited { .. })
+/// ));
+/// ```
+pub struct RateLimitedSecretClient<C: SecretClient> {
+    /// The client the retrievals within the limits are delegated to
+    inner: C,
+    /// The limiter of every key, if enabled
+    per_key: Option<DefaultKeyedRateLimiter<String>>,
+    /// The limiter shared by all keys, if enabled
+    global: Option<DefaultDirectRateLimiter>,
+}
+
+#[async_trait]
+impl<C: SecretClient> SecretClient for RateLimitedSecretClient<C> {
+    /// Retrieves a secret from the inner client unless the rate limit is exceeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::RateLimited)` - If the rate limit is exceeded
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.check(key)?;
+        self.inner.get_by_key(key)
+    }
+
+    /// Lists the keys available from the inner client.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys reported by the inner client
+    /// * `Err(SecretsManagerError)` - If the inner client cannot list its keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.inner.list_keys()
+    }
+
+    /// Retrieves a secret from the inner client asynchronously unless the rate
+    /// limit is exceeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::RateLimited)` - If the rate limit is exceeded
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    async fn get_by_key_async(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        self.check(key)?;
+        self.inner.get_by_key_async(key).await
+    }
+
+    /// Retrieves a secret from the inner client as a JSON object unless the rate
+    /// limit is exceeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, Value>)` - The secret value as a JSON object
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON object
+    /// * `Err(SecretsManagerError::RateLimited)` - If the rate limit is exceeded
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_object_by_key(&self, key: &str) -> Result<Map<String, Value>, SecretsManagerError> {
+        self.check(key)?;
+        self.inner.get_json_object_by_key(key)
+    }
+
+    /// Retrieves a secret from the inner client as a JSON array unless the rate
+    /// limit is exceeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Value>)` - The elements of the JSON array
+    /// * `Err(SecretsManagerError::TypeMismatch)` - If the value is not a JSON array
+    /// * `Err(SecretsManagerError::RateLimited)` - If the rate limit is exceeded
+    /// * `Err(SecretsManagerError)` - If the inner client failed to retrieve the secret
+    fn get_json_array_by_key(&self, key: &str) -> Result<Vec<Value>, SecretsManagerError> {
+        self.check(key)?;
+        self.inner.get_json_array_by_key(key)
+    }
+}
+
+#[async_trait]
+impl<C: HealthCheckableSecretClient> HealthCheckableSecretClient for RateLimitedSecretClient<C> {
+    /// Checks whether the backend of the inner client is reachable.
+    ///
+    /// Health checks are not subject to the rate limits.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the backend of the inner client is reachable, `false` otherwise
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+}
+
+impl<C: SecretClient> RateLimitedSecretClient<C> {
+    /// Consumes a call of the limits of `key`, failing if one of them is exceeded.
+    ///
+    /// The global limit is checked first, so that a call rejected by it does not
+    /// consume a call of the limit of the key.
+    fn check(&self, key: &str) -> Result<(), SecretsManagerError> {
+        if let Some(global) = &self.global {
+            global
+                .check()
+                .map_err(|not_until| rate_limited(key, &not_until))?;
+        }
+
+        if let Some(per_key) = &self.per_key {
+            per_key
+                .check_key(&key.to_string())
+                .map_err(|not_until| rate_limited(key, &not_until))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds the error reported when a retrieval exceeds a rate limit.
+fn rate_limited(
+    key: &str,
+    not_until: &NotUntil<<DefaultClock as Clock>::Instant>,
+) -> SecretsManagerError {
+    let retry_after = not_until.wait_time_from(DefaultClock::default().now());
+
+    warn!(
+        key = key,
+        retry_after_ms = retry_after.as_millis() as u64,
+        "rate limit exceeded for secret {}",
+        key
+    );
+
+    SecretsManagerError::RateLimited {
+        retry_after_ms: retry_after.as_millis() as u64,
+    }
+}
+
+/// Builder for `RateLimitedSecretClient` instances.
+pub struct RateLimitedSecretClientBuilder<C: SecretClient> {
+    /// The client to wrap
+    inner: C,
+    /// The limit of every key, if enabled
+    per_key: Option<Quota>,
+    /// The limit shared by all keys, if enabled
+    global: Option<Quota>,
+}
+
+impl<C: SecretClient> RateLimitedSecretClientBuilder<C> {
+    /// Creates a new builder wrapping the given client, without any limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The client the retrievals within the limits are delegated to
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RateLimitedSecretClientBuilder`
+    pub fn new(inner: C) -> RateLimitedSecretClientBuilder<C> {
+        RateLimitedSecretClientBuilder {
+            inner,
+            per_key: None,
+            global: None,
+        }
+    }
+
+    /// Limits the retrievals of every key to `max_calls` per `period`.
+    ///
+    /// A `max_calls` of zero, or a zero `period`, disables the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_calls` - The number of retrievals of a key allowed per period
+    /// * `period` - The period over which the calls are replenished
+    ///
+    /// # Returns
+    ///
+    /// The builder with the per-key limit configured
+    pub fn with_per_key_limit(
+        mut self,
+        max_calls: u32,
+        period: Duration,
+    ) -> RateLimitedSecretClientBuilder<C> {
+        self.per_key = quota(max_calls, period);
+        self
+    }
+
+    /// Limits the retrievals of all keys together to `max_calls` per `period`.
+    ///
+    /// A `max_calls` of zero, or a zero `period`, disables the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_calls` - The number of retrievals allowed per period
+    /// * `period` - The period over which the calls are replenished
+    ///
+    /// # Returns
+    ///
+    /// The builder with the global limit configured
+    pub fn with_global_limit(
+        mut self,
+        max_calls: u32,
+        period: Duration,
+    ) -> RateLimitedSecretClientBuilder<C> {
+        self.global = quota(max_calls, period);
+        self
+    }
+
+    /// Builds and returns a `RateLimitedSecretClient` instance.
+    ///
+    /// # Returns
+    ///
+    /// A new `RateLimitedSecretClient`
+    pub fn build(self) -> RateLimitedSecretClient<C> {
+        RateLimitedSecretClient {
+            inner: self.inner,
+            per_key: self.per_key.map(RateLimiter::keyed),
+            global: self.global.map(RateLimiter::direct),
+        }
+    }
+}
+
+/// Returns the quota allowing `max_calls` per `period`, if both are non-zero.
+fn quota(max_calls: u32, period: Duration) -> Option<Quota> {
+    let max_calls = NonZeroU32::new(max_calls)?;
+
+    Quota::with_period(period / max_calls.get()).map(|quota| quota.allow_burst(max_calls))
+}
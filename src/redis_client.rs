@@ -0,0 +1,123 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Redis secret client implementation.
+//!
+//! This module provides the `RedisSecretClient` which implements the `SecretClient` trait
+//! for retrieving secrets stored as the fields of a Redis hash.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use redis::{Commands, Connection, RedisError};
+use std::{collections::HashMap, sync::Mutex};
+use tracing::error;
+
+/// Client for accessing secrets stored in a Redis hash.
+///
+/// Each secret is a field of the hash, read with `HGET <hash> <field>`.
+/// Nothing is cached: every call issues a command over a single connection
+/// shared by all callers.
+pub struct RedisSecretClient {
+    /// The connection commands are sent over
+    pub(crate) connection: Mutex<Connection>,
+    /// The key of the hash holding the secrets
+    pub(crate) hash_key: String,
+}
+
+impl SecretClient for RedisSecretClient {
+    /// Retrieves a secret from the hash with `HGET`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash field identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the field is not set
+    /// * `Err(SecretsManagerError)` - If the command failed
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let secret: Option<String> = self.query(|conn| conn.hget(&self.hash_key, key))?;
+
+        let Some(secret) = secret else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(SecretValue::from(secret))
+    }
+
+    /// Checks whether the hash holds the field with `HEXISTS`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The hash field identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is present, `false` otherwise or if the command failed
+    fn contains_key(&self, key: &str) -> bool {
+        self.query(|conn| conn.hexists(&self.hash_key, key))
+            .unwrap_or(false)
+    }
+
+    /// Lists the fields of the hash with `HKEYS`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    /// * `Err(SecretsManagerError)` - If the command failed
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        self.query(|conn| conn.hkeys(&self.hash_key))
+    }
+
+    /// Retrieves every field of the hash with `HGETALL`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - Every secret keyed by its field
+    /// * `Err(SecretsManagerError)` - If the command failed
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        let secrets: HashMap<String, String> = self.query(|conn| conn.hgetall(&self.hash_key))?;
+
+        Ok(secrets
+            .into_iter()
+            .map(|(key, value)| (key, SecretValue::from(value)))
+            .collect())
+    }
+}
+
+impl RedisSecretClient {
+    /// Runs a command over the shared connection and maps its error.
+    fn query<T>(
+        &self,
+        command: impl FnOnce(&mut Connection) -> Result<T, RedisError>,
+    ) -> Result<T, SecretsManagerError> {
+        let mut connection = self.connection.lock().map_err(|_| {
+            error!("redis connection lock was poisoned");
+            SecretsManagerError::internal("redis connection lock was poisoned")
+        })?;
+
+        command(&mut connection).map_err(|err| {
+            error!(error = err.to_string(), "failure send command to redis");
+            map_error(&err)
+        })
+    }
+}
+
+/// Maps a Redis error to the matching `SecretsManagerError`.
+pub(crate) fn map_error(err: &RedisError) -> SecretsManagerError {
+    if err.is_timeout() {
+        return SecretsManagerError::Timeout;
+    }
+
+    if err.is_io_error() || err.is_connection_refusal() || err.is_connection_dropped() {
+        return SecretsManagerError::request_failure(err);
+    }
+
+    SecretsManagerError::BackendError {
+        message: err.to_string(),
+    }
+}
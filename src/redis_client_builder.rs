@@ -0,0 +1,63 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Redis secret client builder module.
+//!
+//! This module provides the `RedisSecretClientBuilder` for constructing instances of
+//! `RedisSecretClient` with appropriate configuration.
+
+use crate::{RedisSecretClient, errors::SecretsManagerError, redis_client::map_error};
+use std::sync::Mutex;
+use tracing::error;
+
+/// Builder for Redis secret client instances.
+///
+/// This builder facilitates the creation of `RedisSecretClient` instances by
+/// opening the connection to the Redis server.
+#[derive(Default)]
+pub struct RedisSecretClientBuilder {
+    /// The URL of the Redis server, e.g. `redis://127.0.0.1:6379`
+    url: String,
+    /// The key of the hash holding the secrets
+    hash_key: String,
+}
+
+impl RedisSecretClientBuilder {
+    /// Creates a new builder for the given Redis hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the Redis server, e.g. `redis://127.0.0.1:6379`
+    /// * `hash_key` - The key of the hash holding the secrets
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RedisSecretClientBuilder`
+    pub fn new(url: &str, hash_key: &str) -> RedisSecretClientBuilder {
+        RedisSecretClientBuilder {
+            url: url.to_string(),
+            hash_key: hash_key.to_string(),
+        }
+    }
+
+    /// Builds and returns a `RedisSecretClient` instance.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RedisSecretClient)` - If the connection was successfully opened
+    /// * `Err(SecretsManagerError)` - If the URL is invalid or the server is unreachable
+    pub fn build(&self) -> Result<RedisSecretClient, SecretsManagerError> {
+        let connection = redis::Client::open(self.url.as_str())
+            .and_then(|client| client.get_connection())
+            .map_err(|err| {
+                error!(error = err.to_string(), "failure to connect to redis");
+                map_error(&err)
+            })?;
+
+        Ok(RedisSecretClient {
+            connection: Mutex::new(connection),
+            hash_key: self.hash_key.clone(),
+        })
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Credentials of an assumed IAM role.
+//!
+//! This module provides the credentials provider behind `AWSSecretClientBuilder::assume_role`
+//! and `AWSSecretClientBuilder::with_web_identity_token_file`. The providers of
+//! `aws_config` for IAM roles resolve the ambient region asynchronously, while the
+//! builder methods are synchronous, so the provider is created on first use.
+
+use aws_config::{
+    Region,
+    provider_config::ProviderConfig,
+    sts::AssumeRoleProvider,
+    web_identity_token::{StaticConfiguration, WebIdentityTokenCredentialsProvider},
+};
+use aws_credential_types::provider::future;
+use aws_sdk_secretsmanager::config::{ProvideCredentials, SharedCredentialsProvider};
+use std::path::PathBuf;
+use tokio::sync::OnceCell;
+
+/// The session name used when assuming a role with a web identity token.
+const WEB_IDENTITY_SESSION_NAME: &str = "secrets-manager";
+
+/// How the credentials of an IAM role are obtained.
+#[derive(Debug)]
+pub(crate) enum Role {
+    /// The role is assumed through STS with base credentials
+    Assumed {
+        /// The ARN of the IAM role to assume
+        role_arn: String,
+        /// The session name identifying the assumed role session
+        session_name: String,
+        /// The credentials the role is assumed with, the default chain if not set
+        base: Option<SharedCredentialsProvider>,
+    },
+    /// The role is assumed through STS with a web identity token, as done on EKS
+    WebIdentity {
+        /// The ARN of the IAM role to assume
+        role_arn: String,
+        /// The path of the web identity token file
+        token_file: PathBuf,
+    },
+}
+
+/// Credentials provider of an IAM role, created on first use.
+#[derive(Debug)]
+pub(crate) struct RoleCredentialsProvider {
+    /// How the credentials of the role are obtained
+    role: Role,
+    /// The region of the STS endpoint, the ambient one if not set
+    region: Option<Region>,
+    /// The provider of the role, once created
+    provider: OnceCell<SharedCredentialsProvider>,
+}
+
+impl RoleCredentialsProvider {
+    /// Creates a new `RoleCredentialsProvider`.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - How the credentials of the role are obtained
+    /// * `region` - The region of the STS endpoint, the ambient one if `None`
+    ///
+    /// # Returns
+    ///
+    /// A new `RoleCredentialsProvider`
+    pub(crate) fn new(role: Role, region: Option<Region>) -> RoleCredentialsProvider {
+        RoleCredentialsProvider {
+            role,
+            region,
+            provider: OnceCell::new(),
+        }
+    }
+
+    /// Creates the `aws_config` provider of the role.
+    async fn create(&self) -> SharedCredentialsProvider {
+        match &self.role {
+            Role::Assumed {
+                role_arn,
+                session_name,
+                base,
+            } => {
+                let mut builder = AssumeRoleProvider::builder(role_arn).session_name(session_name);
+
+                if let Some(region) = &self.region {
+                    builder = builder.region(region.clone());
+                }
+
+                let provider = match base {
+                    Some(base) => builder.build_from_provider(base.clone()).await,
+                    None => builder.build().await,
+                };
+
+                SharedCredentialsProvider::new(provider)
+            }
+            Role::WebIdentity {
+                role_arn,
+                token_file,
+            } => {
+                let config = match &self.region {
+                    Some(region) => ProviderConfig::default().with_region(Some(region.clone())),
+                    None => ProviderConfig::default().load_default_region().await,
+                };
+
+                let provider = WebIdentityTokenCredentialsProvider::builder()
+                    .static_configuration(StaticConfiguration {
+                        web_identity_token_file: token_file.clone(),
+                        role_arn: role_arn.clone(),
+                        session_name: WEB_IDENTITY_SESSION_NAME.to_string(),
+                    })
+                    .configure(&config)
+                    .build();
+
+                SharedCredentialsProvider::new(provider)
+            }
+        }
+    }
+}
+
+impl ProvideCredentials for RoleCredentialsProvider {
+    fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        future::ProvideCredentials::new(async move {
+            self.provider
+                .get_or_init(|| self.create())
+                .await
+                .provide_credentials()
+                .await
+        })
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Secret rotation listener.
+//!
+//! This module provides the `SecretRotationListener` which periodically re-fetches a
+//! secret from AWS Secrets Manager and broadcasts a `SecretChangedEvent` for every
+//! key whose value changed since the previous poll.
+
+use crate::AWSSecretClientBuilder;
+use serde_json::Value;
+use std::{collections::HashMap, time::Duration};
+use tokio::{
+    sync::broadcast::{self, Receiver, Sender},
+    task::JoinHandle,
+};
+use tracing::{debug, error, warn};
+
+/// The number of events buffered for slow subscribers.
+const CHANNEL_CAPACITY: usize = 64;
+
+/// The shortest duration between two polls.
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Notification that the value of a secret key changed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretChangedEvent {
+    /// The key whose value changed
+    pub key: String,
+    /// The previous value, or `None` if the key was added
+    pub old: Option<String>,
+    /// The new value
+    pub new: String,
+}
+
+/// Listener that polls AWS for secret rotations on a background task.
+///
+/// The first successful poll establishes the baseline and emits no events;
+/// every following poll emits one event per added or changed key. The
+/// background task is cancelled by [`SecretRotationListener::stop`] or when
+/// the listener is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use secrets_manager::{AWSSecretClientBuilder, SecretRotationListener};
+/// use std::time::Duration;
+///
+/// async fn example() {
+///     let builder = AWSSecretClientBuilder::new("my-secret-key".to_string());
+///     let listener = SecretRotationListener::start(builder, Duration::from_secs(60));
+///
+///     let mut events = listener.subscribe();
+///     while let Ok(event) = events.recv().await {
+///         println!("secret {} was rotated", event.key);
+///     }
+/// }
+/// ```
+pub struct SecretRotationListener {
+    /// The sender events are broadcast on
+    sender: Sender<SecretChangedEvent>,
+    /// The handle of the polling task
+    handle: JoinHandle<()>,
+}
+
+impl SecretRotationListener {
+    /// Starts polling the secret described by the builder.
+    ///
+    /// Must be called from within a `tokio` runtime. Intervals shorter than a
+    /// second, including a zero interval, are raised to a second, so that a
+    /// misconfigured interval does not flood AWS with requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - The builder used to re-fetch the secret on every poll
+    /// * `interval` - The duration between two polls
+    ///
+    /// # Returns
+    ///
+    /// A new `SecretRotationListener`
+    pub fn start(builder: AWSSecretClientBuilder, interval: Duration) -> SecretRotationListener {
+        if interval < MIN_INTERVAL {
+            warn!(
+                interval_ms = interval.as_millis() as u64,
+                "secret rotation poll interval raised to {:?}", MIN_INTERVAL
+            );
+        }
+
+        let interval = interval.max(MIN_INTERVAL);
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        let handle = tokio::spawn(poll(builder, interval, sender.clone()));
+
+        SecretRotationListener { sender, handle }
+    }
+
+    /// Subscribes to the secret change events.
+    ///
+    /// # Returns
+    ///
+    /// A receiver for the events broadcast after this call
+    pub fn subscribe(&self) -> Receiver<SecretChangedEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Cancels the polling task.
+    pub fn stop(&self) {
+        self.handle.abort();
+    }
+}
+
+impl Drop for SecretRotationListener {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Polls the secret forever, broadcasting the changes between two polls.
+async fn poll(
+    builder: AWSSecretClientBuilder,
+    interval: Duration,
+    sender: Sender<SecretChangedEvent>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    let mut current: Option<HashMap<String, String>> = None;
+
+    loop {
+        ticker.tick().await;
+
+        let client = match builder.build().await {
+            Err(err) => {
+                error!(error = err.to_string(), "failure to poll secret rotation");
+                continue;
+            }
+            Ok(c) => c,
+        };
+
+        let latest = string_values(&client.secrets);
+
+        if let Some(previous) = &current {
+            for (key, new) in &latest {
+                let old = previous.get(key);
+                if old == Some(new) {
+                    continue;
+                }
+
+                debug!(key = key, "secret was rotated");
+
+                // Sending only fails when there are no subscribers, which is not an error
+                let _ = sender.send(SecretChangedEvent {
+                    key: key.clone(),
+                    old: old.cloned(),
+                    new: new.clone(),
+                });
+            }
+        }
+
+        current = Some(latest);
+    }
+}
+
+/// Returns the top-level string values of the given secrets.
+fn string_values(secrets: &Value) -> HashMap<String, String> {
+    let Value::Object(secrets) = secrets else {
+        return HashMap::new();
+    };
+
+    secrets
+        .iter()
+        .filter_map(|(key, value)| value.as_str().map(|v| (key.clone(), v.to_string())))
+        .collect()
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Secret value wrapper that wipes its memory on drop.
+//!
+//! This module provides the `SecretValue` newtype returned by secret clients so
+//! that plaintext secrets do not linger in heap memory once they are no longer used.
+
+use std::{fmt, ops::Deref};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A secret string whose memory is zeroed when it is dropped.
+///
+/// `SecretValue` dereferences to `str` for convenient read access, and its
+/// `Debug` and `Display` implementations print `[REDACTED]` so the secret is
+/// not accidentally written to logs. Secrets are compared with `==` in constant
+/// time, see [`SecretValue::constant_time_eq`].
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::SecretValue;
+///
+/// let secret = SecretValue::from("password".to_string());
+///
+/// assert_eq!(&*secret, "password");
+/// assert_eq!(format!("{secret:?}"), "[REDACTED]");
+/// ```
+#[derive(Clone, Default, Zeroize, ZeroizeOnDrop)]
+pub struct SecretValue(String);
+
+impl SecretValue {
+    /// Consumes the secret and returns the owned plaintext string.
+    ///
+    /// The returned `String` is no longer zeroed on drop, so callers become
+    /// responsible for its lifetime.
+    ///
+    /// # Returns
+    ///
+    /// The secret as a `String`
+    pub fn into_string(mut self) -> String {
+        std::mem::take(&mut self.0)
+    }
+
+    /// Compares the secret with the given bytes in constant time.
+    ///
+    /// Use this method instead of `==` on `str` when comparing a secret, such as
+    /// an API key or an HMAC key, against user-supplied data: a naive comparison
+    /// stops at the first mismatching byte and leaks how much of the input matched
+    /// through its timing (see the OWASP guidance on
+    /// [timing attacks](https://owasp.org/www-community/attacks/Timing_attack)).
+    /// Only the length of the inputs may leak.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The bytes to compare the secret with
+    ///
+    /// # Returns
+    ///
+    /// `true` if the secret is equal to the bytes, `false` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use secrets_manager::SecretValue;
+    ///
+    /// let secret = SecretValue::from("password".to_string());
+    ///
+    /// assert!(secret.constant_time_eq(b"password"));
+    /// assert!(!secret.constant_time_eq(b"passw0rd"));
+    /// ```
+    pub fn constant_time_eq(&self, other: &[u8]) -> bool {
+        self.0.as_bytes().ct_eq(other).into()
+    }
+}
+
+impl ConstantTimeEq for SecretValue {
+    fn ct_eq(&self, other: &SecretValue) -> Choice {
+        self.0.as_bytes().ct_eq(other.0.as_bytes())
+    }
+}
+
+/// Compares two secrets in constant time, so that `==` does not leak through
+/// its timing how much of the secrets matched.
+impl PartialEq for SecretValue {
+    fn eq(&self, other: &SecretValue) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecretValue {}
+
+impl From<String> for SecretValue {
+    fn from(value: String) -> SecretValue {
+        SecretValue(value)
+    }
+}
+
+impl Deref for SecretValue {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SecretValue {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl fmt::Display for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
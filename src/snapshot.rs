@@ -0,0 +1,129 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Point-in-time copy of the secrets of a client.
+//!
+//! This module provides the `SecretSnapshot` which copies every secret of a client
+//! once and serves the copied values afterwards, so that all the reads of a
+//! request observe the same secrets even if the client is reloaded meanwhile.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+use std::{collections::HashMap, fmt};
+use tracing::error;
+
+/// An immutable copy of all the secrets of a client, taken at a point in time.
+///
+/// The snapshot is taken with a single `get_all` call and never changes
+/// afterwards, whatever happens to the client it was taken from. Taking a
+/// snapshot at the start of a request gives consistent reads for the whole
+/// request, even while a `MemorySecretClient` is mutated or an
+/// `AWSSecretClient` reloaded.
+///
+/// # Examples
+///
+/// ```rust
+/// use secrets_manager::{MemorySecretClient, SecretClient, SecretSnapshot};
+///
+/// let client = MemorySecretClient::new();
+/// client.insert("api-key", "old");
+///
+/// let snapshot = SecretSnapshot::from_client(&client).unwrap();
+/// client.insert("api-key", "new");
+///
+/// assert_eq!(&*snapshot.get_by_key("api-key").unwrap(), "old");
+/// assert_eq!(&*client.get_by_key("api-key").unwrap(), "new");
+/// ```
+#[derive(Clone)]
+pub struct SecretSnapshot {
+    /// The secrets at the time the snapshot was taken
+    secrets: HashMap<String, String>,
+}
+
+impl fmt::Debug for SecretSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretSnapshot")
+            .field("keys", &self.secrets.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl SecretClient for SecretSnapshot {
+    /// Retrieves a secret from the snapshot by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The value at the time the snapshot was taken
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the key was absent
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let Some(secret) = self.secrets.get(key) else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(SecretValue::from(secret.clone()))
+    }
+
+    /// Checks whether the snapshot holds the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key was present, `false` otherwise
+    fn contains_key(&self, key: &str) -> bool {
+        self.secrets.contains_key(key)
+    }
+
+    /// Retrieves all the secrets of the snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, SecretValue>)` - The secrets of the snapshot
+    fn get_all(&self) -> Result<HashMap<String, SecretValue>, SecretsManagerError> {
+        Ok(self
+            .secrets
+            .iter()
+            .map(|(key, value)| (key.clone(), SecretValue::from(value.clone())))
+            .collect())
+    }
+
+    /// Lists the keys of the snapshot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The keys of the snapshot
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self.secrets.keys().cloned().collect())
+    }
+}
+
+impl SecretSnapshot {
+    /// Takes a snapshot of all the secrets of a client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client the secrets are copied from
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretSnapshot)` - The snapshot of the secrets
+    /// * `Err(SecretsManagerError)` - If the secrets couldn't be retrieved
+    pub fn from_client(client: &dyn SecretClient) -> Result<SecretSnapshot, SecretsManagerError> {
+        let secrets = client
+            .get_all()?
+            .into_iter()
+            .map(|(key, value)| (key, value.into_string()))
+            .collect();
+
+        Ok(SecretSnapshot { secrets })
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! `tower` middleware injecting a secret client into requests.
+//!
+//! This module provides the `SecretManagerLayer` which wraps a service into a
+//! `SecretManagerService`, inserting a shared `SecretClient` into the extensions
+//! of every request so that handlers of `tower` based frameworks such as axum or
+//! hyper can retrieve secrets.
+
+use crate::SecretClient;
+use http::Request;
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// Layer inserting a shared secret client into the extensions of every request.
+///
+/// Handlers retrieve the client with
+/// `request.extensions().get::<Arc<dyn SecretClient>>()`, or axum's
+/// `Extension<Arc<dyn SecretClient>>` extractor.
+///
+/// # Examples
+///
+/// ```rust
+/// use axum::{Extension, Router, routing::get};
+/// use secrets_manager::{FakeSecretClient, SecretClient, SecretManagerLayer};
+/// use std::sync::Arc;
+///
+/// async fn handler(Extension(secrets): Extension<Arc<dyn SecretClient>>) -> String {
+///     match secrets.get_by_key("api-key") {
+///         Ok(_) => "api key found".to_string(),
+///         Err(err) => err.to_string(),
+///     }
+/// }
+///
+/// let client: Arc<dyn SecretClient> = Arc::new(FakeSecretClient::new());
+/// let app: Router = Router::new()
+///     .route("/", get(handler))
+///     .layer(SecretManagerLayer::new(client));
+/// ```
+#[derive(Clone)]
+pub struct SecretManagerLayer {
+    /// The client inserted into the requests
+    client: Arc<dyn SecretClient>,
+}
+
+impl SecretManagerLayer {
+    /// Creates a new layer inserting the given client into requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client shared by every request
+    ///
+    /// # Returns
+    ///
+    /// A new `SecretManagerLayer`
+    pub fn new(client: Arc<dyn SecretClient>) -> SecretManagerLayer {
+        SecretManagerLayer { client }
+    }
+}
+
+impl<S> Layer<S> for SecretManagerLayer {
+    type Service = SecretManagerService<S>;
+
+    fn layer(&self, inner: S) -> SecretManagerService<S> {
+        SecretManagerService {
+            inner,
+            client: self.client.clone(),
+        }
+    }
+}
+
+/// Service inserting a shared secret client into the extensions of every request
+/// before calling an inner service.
+#[derive(Clone)]
+pub struct SecretManagerService<S> {
+    /// The service called with the requests
+    inner: S,
+    /// The client inserted into the requests
+    client: Arc<dyn SecretClient>,
+}
+
+impl<S, B> Service<Request<B>> for SecretManagerService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> S::Future {
+        request.extensions_mut().insert(self.client.clone());
+        self.inner.call(request)
+    }
+}
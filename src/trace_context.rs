@@ -0,0 +1,56 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! OpenTelemetry trace context propagation.
+//!
+//! This module provides an AWS SDK interceptor injecting the trace context of the
+//! current `tracing` span into the requests sent to Secrets Manager, so that AWS
+//! X-Ray or Jaeger can show Secrets Manager as a dependency of the traced request.
+
+use aws_sdk_secretsmanager::{
+    config::{
+        ConfigBag, Intercept, RuntimeComponents, interceptors::BeforeTransmitInterceptorContextMut,
+    },
+    error::BoxError,
+};
+use opentelemetry::global;
+use std::collections::HashMap;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Interceptor adding the trace context headers of the current span to every request.
+///
+/// The headers are produced by the globally installed text map propagator, e.g.
+/// `traceparent` and `tracestate` for W3C TraceContext. Nothing is added when no
+/// propagator is installed or no OpenTelemetry context is attached to the span.
+#[derive(Debug)]
+pub(crate) struct TraceContextInterceptor;
+
+impl Intercept for TraceContextInterceptor {
+    fn name(&self) -> &'static str {
+        "TraceContextInterceptor"
+    }
+
+    fn modify_before_transmit(
+        &self,
+        context: &mut BeforeTransmitInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let mut fields = HashMap::new();
+        let trace_context = Span::current().context();
+
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&trace_context, &mut fields)
+        });
+
+        let headers = context.request_mut().headers_mut();
+
+        for (name, value) in fields {
+            headers.try_insert(name, value)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! HashiCorp Vault client implementation.
+//!
+//! This module provides the `VaultSecretClient` which implements the `SecretClient` trait
+//! for retrieving secrets from a HashiCorp Vault KV v2 secrets engine.
+
+use crate::{SecretClient, SecretValue, errors::SecretsManagerError};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use std::collections::HashMap;
+use tracing::error;
+
+/// Client for accessing secrets from HashiCorp Vault.
+///
+/// This client stores the fields of a KV v2 secret in memory after they've been
+/// retrieved from Vault and provides methods to access them by key.
+#[derive(Default)]
+pub struct VaultSecretClient {
+    /// The cached secret fields
+    pub(crate) secrets: HashMap<String, String>,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl SecretClient for VaultSecretClient {
+    /// Retrieves a secret from the cached secret fields by its key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key identifying the secret to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SecretValue)` - The secret value
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the secret is not found
+    fn get_by_key(&self, key: &str) -> Result<SecretValue, SecretsManagerError> {
+        let Some(secret) = self.secrets.get(key) else {
+            error!(key = key, "secret {} was not found", key);
+            return Err(SecretsManagerError::SecretNotFound {
+                key: key.to_string(),
+            });
+        };
+
+        Ok(SecretValue::from(secret.clone()))
+    }
+
+    /// Lists the keys of the cached secret fields.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The available secret keys
+    fn list_keys(&self) -> Result<Vec<String>, SecretsManagerError> {
+        Ok(self.secrets.keys().cloned().collect())
+    }
+}
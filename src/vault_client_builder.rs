@@ -0,0 +1,122 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Vault Secret client builder module.
+//!
+//! This module provides the `VaultSecretClientBuilder` for constructing instances of
+//! `VaultSecretClient` with appropriate configuration.
+
+use crate::{VaultSecretClient, errors::SecretsManagerError};
+#[cfg(test)]
+use mockall::*;
+#[cfg(feature = "mocks")]
+use mockall::*;
+use std::collections::HashMap;
+use tracing::error;
+use vaultrs::{
+    client::{VaultClient, VaultClientSettingsBuilder},
+    error::ClientError,
+};
+
+/// Builder for Vault Secret Client instances.
+///
+/// This builder facilitates the creation of `VaultSecretClient` instances
+/// by handling the Vault client configuration and secret retrieval.
+#[derive(Default)]
+pub struct VaultSecretClientBuilder {
+    /// The address of the Vault server
+    vault_addr: String,
+    /// The token used to authenticate against Vault
+    token: String,
+    /// The mount path of the KV v2 secrets engine
+    mount: String,
+    /// The path of the secret within the mount
+    path: String,
+}
+
+#[cfg_attr(test, automock)]
+#[cfg_attr(feature = "mocks", automock)]
+impl VaultSecretClientBuilder {
+    /// Creates a new builder for the given Vault secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_addr` - The address of the Vault server, e.g. `https://vault:8200`
+    /// * `token` - The token used to authenticate against Vault
+    /// * `mount` - The mount path of the KV v2 secrets engine
+    /// * `path` - The path of the secret within the mount
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VaultSecretClientBuilder`
+    pub fn new(vault_addr: &str, token: &str, mount: &str, path: &str) -> VaultSecretClientBuilder {
+        VaultSecretClientBuilder {
+            vault_addr: vault_addr.to_string(),
+            token: token.to_string(),
+            mount: mount.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    /// Builds and returns a `VaultSecretClient` instance.
+    ///
+    /// This asynchronous method:
+    /// 1. Configures the Vault client
+    /// 2. Reads the KV v2 secret
+    /// 3. Creates a `VaultSecretClient` with the secret fields
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VaultSecretClient)` - If the secret was successfully retrieved
+    /// * `Err(SecretsManagerError::SecretNotFound)` - If the mount or the path does not exist
+    /// * `Err(SecretsManagerError::BackendError)` - If the token is denied access to the secret
+    /// * `Err(SecretsManagerError)` - If any other step failed
+    pub async fn build(&self) -> Result<VaultSecretClient, SecretsManagerError> {
+        let settings = match VaultClientSettingsBuilder::default()
+            .address(&self.vault_addr)
+            .token(&self.token)
+            .build()
+        {
+            Err(err) => {
+                error!(error = err.to_string(), "invalid vault client settings");
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(s) => Ok(s),
+        }?;
+
+        let client = match VaultClient::new(settings) {
+            Err(err) => {
+                error!(error = err.to_string(), "failure to create vault client");
+                Err(SecretsManagerError::internal(err))
+            }
+            Ok(c) => Ok(c),
+        }?;
+
+        match vaultrs::kv2::read::<HashMap<String, String>>(&client, &self.mount, &self.path).await
+        {
+            Err(err) => {
+                error!(error = err.to_string(), "failure send request to vault");
+                Err(map_error(err, &self.mount, &self.path))
+            }
+            Ok(secrets) => Ok(VaultSecretClient { secrets }),
+        }
+    }
+}
+
+/// Maps a Vault error to the matching `SecretsManagerError`.
+///
+/// A missing mount or path is reported with the `mount/path` that was requested,
+/// and a denied request as a non-retryable backend error, since retrying it with
+/// the same token cannot succeed.
+fn map_error(err: ClientError, mount: &str, path: &str) -> SecretsManagerError {
+    match err {
+        ClientError::APIError { code: 404, .. } => SecretsManagerError::SecretNotFound {
+            key: format!("{mount}/{path}"),
+        },
+        ClientError::APIError { code: 403, .. } => SecretsManagerError::BackendError {
+            message: err.to_string(),
+        },
+        err => SecretsManagerError::request_failure(err),
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Secret change streams.
+//!
+//! This module defines the `WatchableSecretClient` trait for clients that can
+//! report secret changes as a `Stream`, for use with async reactive frameworks.
+
+use crate::SecretChangedEvent;
+use futures::Stream;
+use std::pin::Pin;
+
+/// Trait for secret clients that can stream the changes of their secrets.
+pub trait WatchableSecretClient {
+    /// Watches the secrets for changes.
+    ///
+    /// The stream polls the secrets for as long as it is polled itself and
+    /// yields one event per added or changed key; the first poll establishes
+    /// the baseline and yields no events.
+    ///
+    /// # Returns
+    ///
+    /// A stream of the changes of the secrets, which does not borrow the client
+    fn watch(&self) -> Pin<Box<dyn Stream<Item = SecretChangedEvent> + Send>>;
+}
@@ -0,0 +1,83 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Integration tests of the axum `InjectableSecret` extractor and `#[derive(SecretKey)]`.
+
+#![cfg(all(feature = "axum", feature = "derive"))]
+
+use async_trait::async_trait;
+use axum::{
+    extract::FromRequestParts,
+    http::{Request, StatusCode},
+    response::IntoResponse,
+};
+use secrets_manager::{
+    InjectableSecret, SecretClient, SecretKey, SecretValue, errors::SecretsManagerError,
+};
+use std::{collections::HashMap, sync::Arc};
+
+#[derive(SecretKey)]
+#[secret_key("api-key")]
+struct ApiKey;
+
+#[derive(SecretKey)]
+#[secret_key("db-pass")]
+struct DbPass;
+
+/// Client whose secrets are only available from memory, failing every fresh retrieval.
+struct LoadedClient;
+
+#[async_trait]
+impl SecretClient for LoadedClient {
+    fn get_by_key(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+        Ok(SecretValue::from("loaded".to_string()))
+    }
+
+    async fn get_by_key_async(&self, _key: &str) -> Result<SecretValue, SecretsManagerError> {
+        Err(SecretsManagerError::Timeout)
+    }
+}
+
+async fn extract<K: SecretKey>(
+    client: Arc<dyn SecretClient>,
+) -> Result<InjectableSecret<K>, StatusCode> {
+    let (mut parts, _) = Request::new(()).into_parts();
+
+    InjectableSecret::<K>::from_request_parts(&mut parts, &client)
+        .await
+        .map_err(|rejection| rejection.into_response().status())
+}
+
+#[test]
+fn derive_secret_key_uses_attribute_key() {
+    assert_eq!(ApiKey::KEY, "api-key");
+    assert_eq!(DbPass::KEY, "db-pass");
+}
+
+#[tokio::test]
+async fn extracts_secret_named_by_derived_key() {
+    let client: Arc<dyn SecretClient> = Arc::new(HashMap::from([("api-key", "value")]));
+
+    let InjectableSecret(secret, ..) = extract::<ApiKey>(client).await.unwrap();
+
+    assert_eq!(secret, "value");
+}
+
+#[tokio::test]
+async fn rejects_missing_secret_with_internal_server_error() {
+    let client: Arc<dyn SecretClient> = Arc::new(HashMap::from([("api-key", "value")]));
+
+    let status = extract::<DbPass>(client).await.unwrap_err();
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn serves_secret_without_fresh_retrieval() {
+    let client: Arc<dyn SecretClient> = Arc::new(LoadedClient);
+
+    let InjectableSecret(secret, ..) = extract::<ApiKey>(client).await.unwrap();
+
+    assert_eq!(secret, "loaded");
+}
@@ -0,0 +1,64 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Integration test of the `SECRETS_MANAGER_<KEY>` overrides of `AWSSecretClient`.
+//!
+//! The test mutates the environment of the process, so it is the only test of
+//! this file, which cargo runs in its own process.
+
+use aws_sdk_secretsmanager::{
+    Client,
+    config::{BehaviorVersion, Config, Region},
+};
+use secrets_manager::{AWSSecretClientBuilder, SecretClient, WarmingStrategy};
+
+/// Builds a lazy client, which does not call AWS until a secret is looked up, and
+/// then only an unreachable local endpoint.
+async fn lazy_client() -> secrets_manager::AWSSecretClient {
+    let sdk_client = Client::from_conf(
+        Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("us-east-1"))
+            .endpoint_url("http://127.0.0.1:1")
+            .build(),
+    );
+
+    AWSSecretClientBuilder::new("infra/db".to_string())
+        .with_sdk_client(sdk_client)
+        .with_warming_strategy(WarmingStrategy::Lazy)
+        .build()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn overrides_are_honored_only_when_allowed_at_build_time() {
+    // SAFETY: this is the only test of the process, so nothing reads the environment concurrently
+    unsafe { std::env::set_var("SECRETS_MANAGER_DB_PASSWORD", "from-env") };
+
+    let disallowed = lazy_client().await;
+    assert!(!disallowed.contains_key("db-password"));
+
+    // SAFETY: see above
+    unsafe { std::env::set_var("SECRETS_MANAGER_ALLOW_ENV_OVERRIDE", "true") };
+    let allowed = lazy_client().await;
+
+    assert_eq!(&*allowed.get_by_key("db-password").unwrap(), "from-env");
+    assert_eq!(
+        &*allowed.get_by_key_async("db-password").await.unwrap(),
+        "from-env"
+    );
+    assert_eq!(&*allowed.get_arc_by_key("db-password").unwrap(), "from-env");
+    assert!(allowed.contains_key("db-password"));
+
+    // SAFETY: see above
+    unsafe { std::env::set_var("SECRETS_MANAGER_DB_PASSWORD", "rotated") };
+    assert_eq!(&*allowed.get_arc_by_key("db-password").unwrap(), "rotated");
+
+    // The flag is read once, when the client is built
+    // SAFETY: see above
+    unsafe { std::env::remove_var("SECRETS_MANAGER_ALLOW_ENV_OVERRIDE") };
+    assert_eq!(&*allowed.get_by_key("db-password").unwrap(), "rotated");
+    assert!(!disallowed.contains_key("db-password"));
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Integration tests of the context carried by `SecretsManagerError`.
+
+use secrets_manager::{
+    AWSSecretClient, SecretClient, SecretClientExt, errors::SecretsManagerError,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn secret_not_found_names_the_missing_key() {
+    let client = AWSSecretClient::try_from(json!({ "db": { "password": "secret" } })).unwrap();
+
+    let err = client.get_by_key("db.username").unwrap_err();
+
+    assert_eq!(
+        err,
+        SecretsManagerError::SecretNotFound {
+            key: "db.username".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "secret db.username not found");
+}
+
+#[test]
+fn aws_secret_was_not_found_names_the_secret_id() {
+    let err = SecretsManagerError::AwsSecretWasNotFound {
+        secret_id: "infra/db".to_string(),
+    };
+
+    assert_eq!(err.to_string(), "aws secret infra/db was not found");
+}
+
+#[test]
+fn aggregate_error_names_every_missing_key() {
+    let client = HashMap::from([("api-key", "value")]);
+
+    let err = client
+        .require_all(&["api-key", "db-user", "db-host"])
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("secret db-user not found"),
+        "{err}"
+    );
+    assert!(
+        err.to_string().contains("secret db-host not found"),
+        "{err}"
+    );
+    assert!(!err.to_string().contains("api-key"), "{err}");
+}
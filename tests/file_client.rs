@@ -0,0 +1,88 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Integration tests of the TOML and `.env` files of `FileSecretClient`.
+
+#![cfg(feature = "file-backend")]
+
+use secrets_manager::{FileSecretClient, SecretClient, errors::SecretsManagerError};
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Returns the path of a test fixture.
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn nested_toml_tables_are_flattened_with_dot_notation() {
+    let client = FileSecretClient::from_toml_file(&fixture("secrets.toml")).unwrap();
+
+    let secrets: HashMap<String, String> = client
+        .get_all()
+        .unwrap()
+        .into_iter()
+        .map(|(key, secret)| (key, secret.into_string()))
+        .collect();
+
+    assert_eq!(
+        secrets,
+        HashMap::from([
+            ("api-key".to_string(), "top-level-key".to_string()),
+            ("database.username".to_string(), "admin".to_string()),
+            ("database.password".to_string(), "x".to_string()),
+            ("database.port".to_string(), "5432".to_string()),
+            ("database.replica.password".to_string(), "y".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn toml_and_json_files_with_the_same_secrets_are_equivalent() {
+    let toml = FileSecretClient::from_toml_file(&fixture("secrets.toml")).unwrap();
+    let json = FileSecretClient::from_json_file(&fixture("secrets.json")).unwrap();
+
+    for key in json.list_keys().unwrap() {
+        assert_eq!(toml.get_by_key(&key), json.get_by_key(&key), "{key}");
+    }
+}
+
+#[test]
+fn toml_values_flattened_to_the_same_key_are_rejected() {
+    let err = FileSecretClient::from_toml_file(&fixture("collision.toml"))
+        .err()
+        .unwrap();
+
+    let SecretsManagerError::DeserializationError(message) = err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert!(message.contains("database.password"), "{message}");
+}
+
+#[test]
+fn quoted_dotenv_values_may_be_followed_by_a_comment() {
+    let client = FileSecretClient::from_dotenv_file(&fixture("secrets.env")).unwrap();
+
+    assert_eq!(&*client.get_by_key("REGION").unwrap(), "eu-west-1");
+    assert_eq!(&*client.get_by_key("MOTTO").unwrap(), "say \"hi\"");
+}
+
+#[test]
+fn dotenv_text_after_a_closing_quote_is_rejected() {
+    let path = env::temp_dir().join(format!("secrets-manager-{}.env", std::process::id()));
+    fs::write(&path, "REGION=\"eu-west-1\" primary\n").unwrap();
+
+    let err = FileSecretClient::from_dotenv_file(&path).err().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let SecretsManagerError::DeserializationError(message) = err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert!(message.contains("line 1"), "{message}");
+}
@@ -0,0 +1,64 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Integration tests of `#[derive(InjectSecrets)]`.
+
+#![cfg(feature = "derive")]
+
+use secrets_manager::{InjectSecrets, SecretValue, errors::SecretsManagerError};
+use std::collections::HashMap;
+
+#[derive(InjectSecrets)]
+struct Config {
+    #[secret("db-pass")]
+    database_password: String,
+    #[secret("sentry-dsn")]
+    sentry_dsn: Option<String>,
+    #[secret("api-key")]
+    api_key: SecretValue,
+    pool_size: u32,
+}
+
+#[test]
+fn annotated_fields_are_populated_from_the_client() {
+    let client = HashMap::from([
+        ("db-pass", "secret"),
+        ("sentry-dsn", "https://sentry.example.com/1"),
+        ("api-key", "key"),
+        ("pool-size", "10"),
+    ]);
+
+    let config = Config::from_secrets(&client).unwrap();
+
+    assert_eq!(config.database_password, "secret");
+    assert_eq!(
+        config.sentry_dsn.as_deref(),
+        Some("https://sentry.example.com/1")
+    );
+    assert_eq!(&*config.api_key, "key");
+    assert_eq!(config.pool_size, 0);
+}
+
+#[test]
+fn missing_optional_secrets_are_none() {
+    let client = HashMap::from([("db-pass", "secret"), ("api-key", "key")]);
+
+    let config = Config::from_secrets(&client).unwrap();
+
+    assert_eq!(config.sentry_dsn, None);
+}
+
+#[test]
+fn missing_required_secrets_are_not_found() {
+    let client = HashMap::from([("db-pass", "secret")]);
+
+    let err = Config::from_secrets(&client).err().unwrap();
+
+    assert_eq!(
+        err,
+        SecretsManagerError::SecretNotFound {
+            key: "api-key".to_string()
+        }
+    );
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2025, The Ruskit Authors
+// MIT License
+// All rights reserved.
+
+//! Integration test of `FileSecretClient::from_sops_json` with a stub `sops` binary.
+//!
+//! The test prepends a directory holding the stub to the `PATH` of the process,
+//! so it is the only test of this file, which cargo runs in its own process.
+
+#![cfg(all(feature = "sops", unix))]
+
+use secrets_manager::{FileSecretClient, SecretClient, errors::SecretsManagerError};
+use std::{env, fs, os::unix::fs::PermissionsExt, path::Path};
+
+/// Stub of `sops` printing the file it is given as if it were decrypted, and
+/// failing like `sops` does for files whose name contains `undecryptable`.
+const SOPS_STUB: &str = r#"#!/bin/sh
+[ "$1 $2 $3" = "--decrypt --output-type json" ] || { echo "unexpected arguments: $*" >&2; exit 2; }
+case "$4" in
+    *undecryptable*) echo "Failed to get the data key required to decrypt the SOPS file." >&2; exit 128 ;;
+esac
+cat "$4"
+"#;
+
+#[test]
+fn sops_files_are_decrypted_with_the_sops_binary() {
+    let bin = env::temp_dir().join(format!("secrets-manager-sops-{}", std::process::id()));
+    fs::create_dir_all(&bin).unwrap();
+    let stub = bin.join("sops");
+    fs::write(&stub, SOPS_STUB).unwrap();
+    fs::set_permissions(&stub, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = env::join_paths(
+        std::iter::once(bin.clone())
+            .chain(env::split_paths(&env::var_os("PATH").unwrap_or_default())),
+    )
+    .unwrap();
+    // SAFETY: this is the only test of the process, so nothing reads the environment concurrently
+    unsafe { env::set_var("PATH", path) };
+
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    let client = FileSecretClient::from_sops_json(&fixtures.join("secrets.json")).unwrap();
+    assert_eq!(&*client.get_by_key("api-key").unwrap(), "top-level-key");
+    assert_eq!(&*client.get_by_key("database.password").unwrap(), "x");
+
+    let err = FileSecretClient::from_sops_json(&fixtures.join("undecryptable.json"))
+        .err()
+        .unwrap();
+    let SecretsManagerError::InternalError { cause } = err else {
+        panic!("unexpected error {err:?}");
+    };
+    assert!(cause.to_string().contains("undecryptable.json"), "{cause}");
+    assert!(
+        cause.to_string().contains("Failed to get the data key"),
+        "{cause}"
+    );
+
+    fs::remove_dir_all(&bin).unwrap();
+}